@@ -1,9 +1,10 @@
 extern crate assimp;
 extern crate cgmath;
+extern crate env_logger;
 #[macro_use]
 extern crate glium;
 
-use assimp::{Importer, LogStream};
+use assimp::Importer;
 use cgmath::{perspective, Matrix4, Deg, Vector3, Point3};
 use glium::{glutin, Surface};
 use glium::index::PrimitiveType;
@@ -21,10 +22,9 @@ fn main() {
     }
     implement_vertex!(Vertex3, position, normal);
 
-    // Setup logging
-    LogStream::set_verbose_logging(true);
-    let mut log_stream = LogStream::stdout();
-    log_stream.attach();
+    // Setup logging, use RUST_LOG=assimp=debug to see Assimp's verbose output
+    env_logger::init();
+    assimp::log::forward_to_log();
 
     // Load shaders
     let program = program!(&display,
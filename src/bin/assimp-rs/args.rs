@@ -0,0 +1,228 @@
+use std::vec;
+
+use assimp::import::ImportSettings;
+use assimp::import::structs::ComponentType;
+
+/// A command-line argument.
+#[derive(Debug, PartialEq)]
+pub enum Arg {
+    /// An option starting with `--`, without the dashes.
+    Option(String),
+    /// Any other argument.
+    Positional(String)
+}
+
+/// Splits command-line arguments into options and positional arguments.
+///
+/// Options take their value either from the same argument (`--name=value`) or from the next
+/// one (`--name value`). Arguments following `--` are all positional.
+pub struct Parser {
+    args: vec::IntoIter<String>,
+    value: Option<String>,
+    positional_only: bool
+}
+
+impl Parser {
+    pub fn new<I: IntoIterator<Item = String>>(args: I) -> Parser {
+        Parser {
+            args: args.into_iter().collect::<Vec<_>>().into_iter(),
+            value: None,
+            positional_only: false
+        }
+    }
+
+    /// Returns the next argument.
+    ///
+    /// Fails if the previous option was given a value with `=` that wasn't read with `value`.
+    pub fn next(&mut self) -> Result<Option<Arg>, String> {
+        if let Some(value) = self.value.take() {
+            return Err(format!("unexpected value '{}'", value));
+        }
+        let arg = match self.args.next() {
+            Some(arg) => arg,
+            None => return Ok(None)
+        };
+        if self.positional_only || !arg.starts_with("--") {
+            return Ok(Some(Arg::Positional(arg)));
+        }
+        if arg == "--" {
+            self.positional_only = true;
+            return self.next();
+        }
+        match arg.find('=') {
+            Some(idx) => {
+                self.value = Some(arg[idx + 1..].to_owned());
+                Ok(Some(Arg::Option(arg[2..idx].to_owned())))
+            }
+            None => Ok(Some(Arg::Option(arg[2..].to_owned())))
+        }
+    }
+
+    /// Returns the value of the option `name` which was just returned by `next`.
+    pub fn value(&mut self, name: &str) -> Result<String, String> {
+        self.value.take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| format!("missing value for --{}", name))
+    }
+
+    /// Returns the value of the option `name` parsed as a `T`.
+    pub fn parse_value<T: ::std::str::FromStr>(&mut self, name: &str) -> Result<T, String> {
+        let value = self.value(name)?;
+        value.parse().map_err(|_| format!("invalid value for --{}: '{}'", name, value))
+    }
+}
+
+/// Help text for the options handled by `PostProcessArgs`.
+pub const POST_PROCESS_HELP: &'static str = "\
+Post-processing options:
+    --preset <name>              Start from a preset: fast, quality or max-quality
+    --left-handed                Convert to a left-handed coordinate system
+    --triangulate                Split polygons into triangles
+    --gen-normals                Generate flat normals for meshes without normals
+    --gen-smooth-normals         Generate smooth normals for meshes without normals
+    --force-gen-normals          Replace existing normals when generating normals
+    --drop-normals               Remove normals, e.g. to generate new ones
+    --calc-tangents              Compute tangents and bitangents
+    --join-vertices              Join identical vertices
+    --flip-uvs                   Flip texture coordinates vertically
+    --flip-winding               Flip the winding order of faces
+    --gen-uv-coords              Convert non-UV mappings to texture coordinates
+    --transform-uv-coords        Apply UV transformations to texture coordinates
+    --pre-transform              Pre-transform vertices and flatten the hierarchy
+    --limit-bone-weights         Limit the number of bones affecting a vertex
+    --debone                     Remove bones which can be removed without losing precision
+    --populate-armature-data     Link bones to their nodes
+    --improve-cache-locality     Reorder triangles for better vertex cache locality
+    --remove-redundant-materials Remove duplicate and unused materials
+    --fix-normals                Fix normals pointing inwards
+    --sort-by-type               Split meshes by primitive type
+    --find-degenerates           Remove degenerate primitives
+    --find-invalid-data          Remove invalid data
+    --find-instances             Merge duplicate meshes
+    --optimize-meshes            Merge small meshes
+    --optimize-graph             Collapse the node hierarchy
+    --split-large-meshes         Split meshes with too many vertices or triangles
+    --embed-textures             Embed external textures in the scene
+    --remove-component <list>    Remove components, given as a comma-separated list of
+                                 normals, tangents, colors, texcoords, bone-weights,
+                                 animations, textures, lights, cameras, meshes, materials
+    --gen-bounding-boxes         Compute mesh bounding boxes (not readable by assimp-rs)
+    --validate                   Validate the imported data structure
+    --global-scale <factor>      Scale the scene by the given factor
+";
+
+/// Builds import settings from the post-processing options given on the command line.
+pub struct PostProcessArgs {
+    settings: ImportSettings,
+    modified: bool
+}
+
+impl PostProcessArgs {
+    pub fn new() -> PostProcessArgs {
+        PostProcessArgs { settings: ImportSettings::default(), modified: false }
+    }
+
+    /// Replaces the settings, e.g. with ones loaded from a file. Options given afterwards are
+    /// applied on top of them.
+    pub fn set_base(&mut self, settings: ImportSettings) -> Result<(), String> {
+        if self.modified {
+            return Err("presets and profiles must be given before other post-processing options"
+                .to_owned());
+        }
+        self.settings = settings;
+        Ok(())
+    }
+
+    /// Applies the option `name` if it's a post-processing option.
+    ///
+    /// Returns whether the option was recognized.
+    pub fn parse(&mut self, name: &str, parser: &mut Parser) -> Result<bool, String> {
+        if name == "preset" {
+            let preset = match &*parser.value(name)? {
+                "fast" => ImportSettings::target_realtime_fast(),
+                "quality" => ImportSettings::target_realtime_quality(),
+                "max-quality" => ImportSettings::target_realtime_max_quality(),
+                other => return Err(format!("unknown preset '{}'", other))
+            };
+            self.set_base(preset)?;
+            return Ok(true);
+        }
+
+        let settings = &mut self.settings;
+        match name {
+            "left-handed" => *settings = settings.clone().convert_to_left_handed(),
+            "triangulate" => settings.triangulate = true,
+            "gen-normals" => settings.generate_normals.enable = true,
+            "gen-smooth-normals" => {
+                settings.generate_normals.enable = true;
+                settings.generate_normals.smooth = true;
+            }
+            "force-gen-normals" => {
+                settings.generate_normals.enable = true;
+                settings.generate_normals.force = true;
+            }
+            "drop-normals" => settings.drop_normals = true,
+            "calc-tangents" => settings.calc_tangent_space.enable = true,
+            "join-vertices" => settings.join_identical_vertices = true,
+            "flip-uvs" => settings.flip_uvs = true,
+            "flip-winding" => settings.flip_winding_order = true,
+            "gen-uv-coords" => settings.gen_uv_coords = true,
+            "transform-uv-coords" => settings.transform_uv_coords.enable = true,
+            "pre-transform" => settings.pre_transform_vertices.enable = true,
+            "limit-bone-weights" => settings.limit_bone_weights.enable = true,
+            "debone" => settings.debone.enable = true,
+            "populate-armature-data" => settings.populate_armature_data = true,
+            "improve-cache-locality" => settings.improve_cache_locality.enable = true,
+            "remove-redundant-materials" => settings.remove_redundant_materials.enable = true,
+            "fix-normals" => settings.fix_infacing_normals = true,
+            "sort-by-type" => settings.sort_by_primitive_type.enable = true,
+            "find-degenerates" => settings.find_degenerates.enable = true,
+            "find-invalid-data" => settings.find_invalid_data.enable = true,
+            "find-instances" => settings.find_instances = true,
+            "optimize-meshes" => settings.optimize_meshes = true,
+            "optimize-graph" => settings.optimize_graph.enable = true,
+            "split-large-meshes" => settings.split_large_meshes.enable = true,
+            "embed-textures" => settings.embed_textures = true,
+            "remove-component" => {
+                settings.remove_component.enable = true;
+                for component in parser.value(name)?.split(',') {
+                    let component = parse_component(component)
+                        .ok_or_else(|| format!("unknown component '{}'", component))?;
+                    if !settings.remove_component.components.contains(&component) {
+                        settings.remove_component.components.push(component);
+                    }
+                }
+            }
+            "gen-bounding-boxes" => settings.gen_bounding_boxes = true,
+            "validate" => settings.validate_data_structure = true,
+            "global-scale" => {
+                settings.global_scale.enable = true;
+                settings.global_scale.scale_factor = parser.parse_value(name)?;
+            }
+            _ => return Ok(false)
+        }
+        self.modified = true;
+        Ok(true)
+    }
+
+    pub fn settings(&self) -> &ImportSettings {
+        &self.settings
+    }
+}
+
+fn parse_component(name: &str) -> Option<ComponentType> {
+    match name.trim() {
+        "normals" => Some(ComponentType::Normals),
+        "tangents" => Some(ComponentType::TangentsAndBitangents),
+        "colors" => Some(ComponentType::Colors),
+        "texcoords" => Some(ComponentType::TexCoords),
+        "bone-weights" => Some(ComponentType::BoneWeights),
+        "animations" => Some(ComponentType::Animations),
+        "textures" => Some(ComponentType::Textures),
+        "lights" => Some(ComponentType::Lights),
+        "cameras" => Some(ComponentType::Cameras),
+        "meshes" => Some(ComponentType::Meshes),
+        "materials" => Some(ComponentType::Materials),
+        _ => None
+    }
+}
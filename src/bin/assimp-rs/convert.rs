@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Instant;
+
+use assimp::export::{ExportFormat, Exporter};
+use assimp::import::{import_batch_process, BatchOptions, BatchResult, ImportSettings};
+use glob;
+use serde::Serialize;
+use serde_json;
+use toml;
+
+use args::{Arg, Parser, PostProcessArgs, POST_PROCESS_HELP};
+use info::severity_name;
+
+pub const USAGE: &'static str = "\
+Usage: assimp-rs convert [options] --output <dir> --format <format> <input>...
+
+Imports each input file, post-processes it and exports it to the output directory, keeping its
+file name with the extension of the output format. Inputs may be glob patterns, e.g.
+'models/**/*.fbx'. Files are converted in parallel.
+
+The profile is a TOML file using the field names of `ImportSettings`, e.g.:
+
+    triangulate = true
+    join_identical_vertices = true
+
+    [generate_normals]
+    enable = true
+    smooth = true
+
+Options:
+    --output <dir>               Directory to write the converted files to
+    --format <format>            Identifier or extension of the output format
+    --profile <file>             Import settings to use, applied before other options
+    --jobs <n>                   Number of files converted at once, defaults to the
+                                 number of CPUs
+    --summary <file>             Write a JSON summary of the conversion, '-' for stdout
+    --list-formats               List the output formats and exit
+    -h, --help                   Print this message
+";
+
+/// Outcome of the conversion of all files, written by `--summary`.
+#[derive(Serialize)]
+struct Summary {
+    format: String,
+    output_dir: String,
+    succeeded: usize,
+    failed: usize,
+    files: Vec<FileResult>
+}
+
+#[derive(Serialize)]
+struct FileResult {
+    input: String,
+    output: String,
+    success: bool,
+    error: Option<String>,
+    warnings: Vec<String>,
+    /// Time taken to import and export the file, in milliseconds.
+    time_ms: u64
+}
+
+struct Job {
+    input: String,
+    output: PathBuf
+}
+
+pub fn run<I: IntoIterator<Item = String>>(args: I) -> Result<(), String> {
+    let mut parser = Parser::new(args);
+    let mut post_process = PostProcessArgs::new();
+    let mut output_dir = None;
+    let mut format = None;
+    let mut jobs = None;
+    let mut summary_path = None;
+    let mut patterns = Vec::new();
+
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Option(name) => match &*name {
+                "output" => output_dir = Some(PathBuf::from(parser.value(&name)?)),
+                "format" => format = Some(parser.value(&name)?),
+                "profile" => post_process.set_base(load_profile(&parser.value(&name)?)?)?,
+                "jobs" => jobs = Some(parser.parse_value::<usize>(&name)?.max(1)),
+                "summary" => summary_path = Some(parser.value(&name)?),
+                "list-formats" => {
+                    for format in ExportFormat::all() {
+                        println!("{:<12} {:<8} {}", format.id, format.extension, format.description);
+                    }
+                    return Ok(());
+                }
+                "help" => {
+                    print!("{}\n{}", USAGE, POST_PROCESS_HELP);
+                    return Ok(());
+                }
+                _ => {
+                    if !post_process.parse(&name, &mut parser)? {
+                        return Err(format!("unknown option --{}", name));
+                    }
+                }
+            },
+            Arg::Positional(ref arg) if arg == "-h" => {
+                print!("{}\n{}", USAGE, POST_PROCESS_HELP);
+                return Ok(());
+            }
+            Arg::Positional(arg) => patterns.push(arg)
+        }
+    }
+
+    let output_dir = output_dir.ok_or_else(|| format!("missing --output\n\n{}", USAGE))?;
+    let format = format.ok_or_else(|| format!("missing --format\n\n{}", USAGE))?;
+    let format = ExportFormat::from_id(&format)
+        .or_else(|| ExportFormat::for_file(&format))
+        .ok_or_else(|| format!("unknown format '{}', see --list-formats", format))?;
+    if patterns.is_empty() {
+        return Err(format!("missing input files\n\n{}", USAGE));
+    }
+
+    let inputs = expand_inputs(&patterns)?;
+    let jobs_list = plan_jobs(inputs, &output_dir, &format.extension)?;
+    fs::create_dir_all(&output_dir)
+        .map_err(|e| format!("failed to create {}: {}", output_dir.display(), e))?;
+
+    let threads = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |x| x.get()));
+    let files = convert_all(jobs_list, post_process.settings(), Exporter::with_format(format.clone()),
+                            threads);
+
+    let failed = files.iter().filter(|x| !x.success).count();
+    let summary = Summary {
+        format: format.id,
+        output_dir: output_dir.to_string_lossy().into_owned(),
+        succeeded: files.len() - failed,
+        failed: failed,
+        files: files
+    };
+    if let Some(path) = summary_path {
+        write_summary(&summary, &path)?;
+    }
+
+    if failed > 0 {
+        Err(format!("{} of {} files failed to convert", failed, summary.files.len()))
+    } else {
+        Ok(())
+    }
+}
+
+fn load_profile(path: &str) -> Result<ImportSettings, String> {
+    let mut text = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut text))
+        .map_err(|e| format!("failed to read profile {}: {}", path, e))?;
+    toml::from_str(&text).map_err(|e| format!("invalid profile {}: {}", path, e))
+}
+
+// Expands glob patterns, keeping files in the order they're first matched.
+fn expand_inputs(patterns: &[String]) -> Result<Vec<String>, String> {
+    let mut inputs = Vec::new();
+    for pattern in patterns {
+        let paths = glob::glob(pattern).map_err(|e| format!("invalid pattern '{}': {}", pattern, e))?;
+        let mut matched = false;
+        for path in paths {
+            let path = path.map_err(|e| e.to_string())?;
+            if path.is_file() {
+                let path = path.to_string_lossy().into_owned();
+                if !inputs.contains(&path) {
+                    inputs.push(path);
+                }
+                matched = true;
+            }
+        }
+        if !matched {
+            return Err(format!("no files match '{}'", pattern));
+        }
+    }
+    Ok(inputs)
+}
+
+// Assigns an output file to each input, failing if two inputs would overwrite each other.
+fn plan_jobs(inputs: Vec<String>, output_dir: &Path, extension: &str) -> Result<Vec<Job>, String> {
+    let mut outputs: HashMap<PathBuf, String> = HashMap::new();
+    let mut jobs = Vec::new();
+    for input in inputs {
+        let stem = Path::new(&input).file_stem().map(|x| x.to_owned()).unwrap_or_default();
+        let output = output_dir.join(stem).with_extension(extension);
+        if let Some(other) = outputs.get(&output) {
+            return Err(format!("{} and {} would both be converted to {}", other, input,
+                               output.display()));
+        }
+        outputs.insert(output.clone(), input.clone());
+        jobs.push(Job { input: input, output: output });
+    }
+    Ok(jobs)
+}
+
+// Converts the files on `threads` threads, and returns the results in the order of `jobs`.
+// Each scene is exported by the thread which imported it, and dropped afterwards.
+fn convert_all(jobs: Vec<Job>, settings: &ImportSettings, exporter: Exporter, threads: usize)
+    -> Vec<FileResult>
+{
+    let total = jobs.len();
+    let inputs: Vec<&str> = jobs.iter().map(|job| &*job.input).collect();
+    let mut results: Vec<Option<FileResult>> = (0..total).map(|_| None).collect();
+    let stderr = io::stderr();
+
+    let options = BatchOptions { concurrency: threads };
+    import_batch_process(&inputs, settings, &options, |index, import| {
+        export_file(&exporter, &jobs[index], import)
+    }, |progress, result| {
+        let mut err = stderr.lock();
+        match result.error {
+            None => {
+                let _ = writeln!(err, "[{}/{}] {} -> {}", progress.completed, total, result.input,
+                                 result.output);
+            }
+            Some(ref error) => {
+                let _ = writeln!(err, "[{}/{}] {} failed: {}", progress.completed, total, result.input,
+                                 error);
+            }
+        }
+        results[progress.index] = Some(result);
+    });
+
+    // Panics in workers are propagated, so every file has a result
+    results.into_iter().map(|x| x.unwrap()).collect()
+}
+
+fn export_file(exporter: &Exporter, job: &Job, import: BatchResult) -> FileResult {
+    let start = Instant::now();
+    let output = job.output.to_string_lossy().into_owned();
+    let result = import.scene
+        .map_err(|e| format!("import failed: {}", e))
+        .and_then(|scene| {
+            exporter.export_file(&scene, &output).map_err(|e| format!("export failed: {}", e))
+        });
+
+    let warnings = import.messages.iter()
+        .map(|msg| format!("{}: {}", severity_name(msg.severity), msg.text))
+        .collect();
+    let elapsed = import.duration + start.elapsed();
+    FileResult {
+        input: job.input.clone(),
+        output: output,
+        success: result.is_ok(),
+        error: result.err(),
+        warnings: warnings,
+        time_ms: elapsed.as_secs() * 1000 + u64::from(elapsed.subsec_millis())
+    }
+}
+
+fn write_summary(summary: &Summary, path: &str) -> Result<(), String> {
+    if path == "-" {
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+        serde_json::to_writer_pretty(&mut out, summary).map_err(|e| e.to_string())?;
+        writeln!(out).map_err(|e| e.to_string())
+    } else {
+        let file = File::create(path).map_err(|e| format!("failed to create {}: {}", path, e))?;
+        serde_json::to_writer_pretty(file, summary)
+            .map_err(|e| format!("failed to write {}: {}", path, e))
+    }
+}
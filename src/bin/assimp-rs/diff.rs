@@ -0,0 +1,117 @@
+use std::io::{self, Write};
+
+use assimp::import::Importer;
+use assimp::scene::{DiffOptions, Scene};
+use serde_json;
+
+use args::{Arg, Parser, PostProcessArgs, POST_PROCESS_HELP};
+use info::print_warnings;
+
+pub const USAGE: &'static str = "\
+Usage: assimp-rs diff [options] <old> <new>
+
+Compares two models, importing both with the same post-processing options, and prints their
+differences. Exits with an error if the models differ.
+
+Options:
+    --tolerance <f>              Tolerance of values without a specific tolerance below,
+                                 defaults to 0.0001
+    --transform-tolerance <f>    Tolerance of node transformations
+    --vertex-tolerance <f>       Tolerance of vertex attributes
+    --material-tolerance <f>     Tolerance of numeric material properties
+    --animation-tolerance <f>    Tolerance of animation keys and durations
+    --json                       Print the differences as JSON
+    -h, --help                   Print this message
+";
+
+pub fn run<I: IntoIterator<Item = String>>(args: I) -> Result<(), String> {
+    let mut parser = Parser::new(args);
+    let mut post_process = PostProcessArgs::new();
+    // Specific tolerances take precedence over `--tolerance`, whatever their order
+    let mut tolerance = None;
+    let mut transform_tolerance = None;
+    let mut vertex_tolerance = None;
+    let mut material_tolerance = None;
+    let mut animation_tolerance = None;
+    let mut json = false;
+    let mut files = Vec::new();
+
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Option(name) => match &*name {
+                "tolerance" => tolerance = Some(parse_tolerance(&mut parser, &name)?),
+                "transform-tolerance" => transform_tolerance = Some(parse_tolerance(&mut parser, &name)?),
+                "vertex-tolerance" => vertex_tolerance = Some(parse_tolerance(&mut parser, &name)?),
+                "material-tolerance" => material_tolerance = Some(parse_tolerance(&mut parser, &name)?),
+                "animation-tolerance" => animation_tolerance = Some(parse_tolerance(&mut parser, &name)?),
+                "json" => json = true,
+                "help" => {
+                    print!("{}\n{}", USAGE, POST_PROCESS_HELP);
+                    return Ok(());
+                }
+                _ => {
+                    if !post_process.parse(&name, &mut parser)? {
+                        return Err(format!("unknown option --{}", name));
+                    }
+                }
+            },
+            Arg::Positional(ref arg) if arg == "-h" => {
+                print!("{}\n{}", USAGE, POST_PROCESS_HELP);
+                return Ok(());
+            }
+            Arg::Positional(arg) => {
+                if files.len() == 2 {
+                    return Err(format!("unexpected argument '{}'", arg));
+                }
+                files.push(arg);
+            }
+        }
+    }
+    if files.len() < 2 {
+        return Err(format!("expected two files to compare\n\n{}", USAGE));
+    }
+    let defaults = tolerance.map_or_else(DiffOptions::default, DiffOptions::with_tolerance);
+    let options = DiffOptions {
+        transform_tolerance: transform_tolerance.unwrap_or(defaults.transform_tolerance),
+        vertex_tolerance: vertex_tolerance.unwrap_or(defaults.vertex_tolerance),
+        material_tolerance: material_tolerance.unwrap_or(defaults.material_tolerance),
+        animation_tolerance: animation_tolerance.unwrap_or(defaults.animation_tolerance)
+    };
+
+    let importer = Importer::with_settings(post_process.settings());
+    let old = import(&importer, &files[0])?;
+    let new = import(&importer, &files[1])?;
+    let diff = old.diff(&new, &options);
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    if json {
+        serde_json::to_writer_pretty(&mut out, &diff).map_err(|e| e.to_string())?;
+        writeln!(out).map_err(|e| e.to_string())?;
+    } else {
+        write!(out, "{}", diff).map_err(|e| e.to_string())?;
+    }
+
+    if diff.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} differences found", diff.differences.len()))
+    }
+}
+
+// Parses the value of a tolerance option, which can't be negative.
+fn parse_tolerance(parser: &mut Parser, name: &str) -> Result<f32, String> {
+    let tolerance = parser.parse_value::<f32>(name)?;
+    if tolerance < 0.0 {
+        return Err(format!("--{} can't be negative", name));
+    }
+    Ok(tolerance)
+}
+
+fn import<'a>(importer: &Importer, file: &str) -> Result<Scene<'a>, String> {
+    let (scene, messages) = importer.read_file_with_log(file);
+    scene.map_err(|err| {
+        print_warnings(&mut io::stderr(), &messages);
+        format!("failed to import {}: {}", file, err)
+    })
+}
@@ -0,0 +1,281 @@
+use std::io::{self, Write};
+
+use assimp::import::Importer;
+use assimp::log::{LogMessage, Severity};
+use assimp::scene::{texture_type_name, MaterialPropertyKey, Node, PropertyValue, Scene, SceneStats,
+                    DEFAULT_TICKS_PER_SECOND};
+use serde::Serialize;
+use serde_json;
+
+use args::{Arg, Parser, PostProcessArgs, POST_PROCESS_HELP};
+
+pub const USAGE: &'static str = "\
+Usage: assimp-rs info [options] <file>
+
+Prints the node hierarchy, meshes, materials and animations of a model as imported by
+assimp-rs, along with the warnings logged while importing it.
+
+Options:
+    --json                       Print the information as JSON
+    -h, --help                   Print this message
+";
+
+#[derive(Serialize)]
+struct Info {
+    file: String,
+    stats: SceneStats,
+    hierarchy: Option<NodeInfo>,
+    materials: Vec<MaterialInfo>,
+    animations: Vec<AnimationInfo>,
+    warnings: Vec<Warning>
+}
+
+#[derive(Serialize)]
+struct NodeInfo {
+    name: String,
+    meshes: Vec<u32>,
+    children: Vec<NodeInfo>
+}
+
+#[derive(Serialize)]
+struct MaterialInfo {
+    name: String,
+    properties: u32,
+    textures: Vec<TextureInfo>
+}
+
+#[derive(Serialize)]
+struct TextureInfo {
+    #[serde(rename = "type")]
+    texture_type: &'static str,
+    index: u32,
+    path: String
+}
+
+#[derive(Serialize)]
+struct AnimationInfo {
+    name: String,
+    /// Duration in seconds.
+    duration: f64,
+    ticks_per_second: f64,
+    channels: u32
+}
+
+#[derive(Serialize)]
+struct Warning {
+    severity: &'static str,
+    text: String
+}
+
+pub fn run<I: IntoIterator<Item = String>>(args: I) -> Result<(), String> {
+    let mut parser = Parser::new(args);
+    let mut post_process = PostProcessArgs::new();
+    let mut json = false;
+    let mut file = None;
+
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Option(ref name) if name == "json" => json = true,
+            Arg::Option(ref name) if name == "help" => {
+                print!("{}\n{}", USAGE, POST_PROCESS_HELP);
+                return Ok(());
+            }
+            Arg::Option(name) => {
+                if !post_process.parse(&name, &mut parser)? {
+                    return Err(format!("unknown option --{}", name));
+                }
+            }
+            Arg::Positional(ref arg) if arg == "-h" => {
+                print!("{}\n{}", USAGE, POST_PROCESS_HELP);
+                return Ok(());
+            }
+            Arg::Positional(arg) => {
+                if file.is_some() {
+                    return Err(format!("unexpected argument '{}'", arg));
+                }
+                file = Some(arg);
+            }
+        }
+    }
+    let file = file.ok_or_else(|| format!("missing input file\n\n{}", USAGE))?;
+
+    let importer = Importer::with_settings(post_process.settings());
+    let (scene, messages) = importer.read_file_with_log(&file);
+    let scene = match scene {
+        Ok(scene) => scene,
+        Err(err) => {
+            print_warnings(&mut io::stderr(), &messages);
+            return Err(format!("failed to import {}: {}", file, err));
+        }
+    };
+
+    let info = collect(file, &scene, &messages);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    if json {
+        serde_json::to_writer_pretty(&mut out, &info).map_err(|e| e.to_string())?;
+        writeln!(out).map_err(|e| e.to_string())
+    } else {
+        print_info(&mut out, &info).map_err(|e| e.to_string())
+    }
+}
+
+fn collect(file: String, scene: &Scene, messages: &[LogMessage]) -> Info {
+    let hierarchy = if scene.root_node.is_null() {
+        None
+    } else {
+        Some(node_info(&scene.root_node()))
+    };
+
+    let materials = scene.material_iter().map(|material| {
+        let assimp5 = material.has_assimp5_texture_types();
+        let textures = material.properties_iter()
+            .filter(|prop| prop.key() == MaterialPropertyKey::TexturePath)
+            .filter_map(|prop| match prop.value() {
+                PropertyValue::String(path) => Some(TextureInfo {
+                    texture_type: texture_type_name(prop.semantic(), assimp5),
+                    index: prop.index(),
+                    path: path
+                }),
+                _ => None
+            })
+            .collect();
+        MaterialInfo {
+            name: material.name().map(|x| x.as_ref().to_owned()).unwrap_or_default(),
+            properties: material.num_properties(),
+            textures: textures
+        }
+    }).collect();
+
+    let animations = scene.animation_iter().map(|anim| {
+        let ticks_per_second = if anim.ticks_per_second > 0.0 {
+            anim.ticks_per_second
+        } else {
+            DEFAULT_TICKS_PER_SECOND
+        };
+        AnimationInfo {
+            name: anim.name.as_ref().to_owned(),
+            duration: anim.duration / ticks_per_second,
+            ticks_per_second: anim.ticks_per_second,
+            channels: anim.num_channels
+        }
+    }).collect();
+
+    let warnings = messages.iter().map(|msg| {
+        Warning {
+            severity: severity_name(msg.severity),
+            text: msg.text.clone()
+        }
+    }).collect();
+
+    Info {
+        file: file,
+        stats: scene.stats(),
+        hierarchy: hierarchy,
+        materials: materials,
+        animations: animations,
+        warnings: warnings
+    }
+}
+
+fn node_info(node: &Node) -> NodeInfo {
+    NodeInfo {
+        name: node.name().to_owned(),
+        meshes: node.meshes().to_vec(),
+        children: node.child_iter().map(|child| node_info(&child)).collect()
+    }
+}
+
+fn print_info<W: Write>(out: &mut W, info: &Info) -> io::Result<()> {
+    let stats = &info.stats;
+    writeln!(out, "File:        {}", info.file)?;
+    writeln!(out, "Meshes:      {}", stats.meshes.len())?;
+    writeln!(out, "Vertices:    {}", stats.vertices)?;
+    writeln!(out, "Faces:       {} ({} triangles, {} polygons, {} lines, {} points)",
+             stats.faces, stats.triangles, stats.polygons, stats.lines, stats.points)?;
+    writeln!(out, "Bones:       {} ({} unique)", stats.bones, stats.unique_bones)?;
+    writeln!(out, "Materials:   {}", stats.materials)?;
+    writeln!(out, "Textures:    {} embedded, {} external", stats.embedded_textures,
+             stats.external_textures)?;
+    writeln!(out, "Animations:  {} ({:.2}s total)", stats.animations, stats.animation_duration)?;
+    writeln!(out, "Cameras:     {}", stats.cameras)?;
+    writeln!(out, "Lights:      {}", stats.lights)?;
+    writeln!(out, "Nodes:       {} (depth {})", stats.nodes, stats.node_depth)?;
+
+    if let Some(ref root) = info.hierarchy {
+        writeln!(out, "\nHierarchy:")?;
+        print_node(out, root, 1)?;
+    }
+
+    if !stats.meshes.is_empty() {
+        writeln!(out, "\nMeshes:")?;
+        writeln!(out, "  {:>4}  {:<24} {:>8} {:>8} {:>8} {:>8} {:>6} {:>4} {:>6} {:>8}",
+                 "#", "name", "vertices", "faces", "tris", "polys", "bones", "uvs", "colors",
+                 "material")?;
+        for (i, mesh) in stats.meshes.iter().enumerate() {
+            writeln!(out, "  {:>4}  {:<24} {:>8} {:>8} {:>8} {:>8} {:>6} {:>4} {:>6} {:>8}",
+                     i, mesh.name, mesh.vertices, mesh.faces, mesh.triangles, mesh.polygons,
+                     mesh.bones, mesh.uv_channels, mesh.color_sets, mesh.material_index)?;
+        }
+    }
+
+    if !info.materials.is_empty() {
+        writeln!(out, "\nMaterials:")?;
+        for (i, material) in info.materials.iter().enumerate() {
+            writeln!(out, "  {:>4}  {} ({} properties)", i, material.name, material.properties)?;
+            for texture in &material.textures {
+                writeln!(out, "          {}[{}]: {}", texture.texture_type, texture.index,
+                         texture.path)?;
+            }
+        }
+    }
+
+    if !info.animations.is_empty() {
+        writeln!(out, "\nAnimations:")?;
+        writeln!(out, "  {:>4}  {:<24} {:>10} {:>10} {:>8}",
+                 "#", "name", "duration", "ticks/s", "channels")?;
+        for (i, anim) in info.animations.iter().enumerate() {
+            writeln!(out, "  {:>4}  {:<24} {:>9.2}s {:>10} {:>8}",
+                     i, anim.name, anim.duration, anim.ticks_per_second, anim.channels)?;
+        }
+    }
+
+    if !info.warnings.is_empty() {
+        writeln!(out, "\nWarnings:")?;
+        for warning in &info.warnings {
+            writeln!(out, "  {}: {}", warning.severity, warning.text)?;
+        }
+    }
+    Ok(())
+}
+
+fn print_node<W: Write>(out: &mut W, node: &NodeInfo, depth: usize) -> io::Result<()> {
+    let meshes: Vec<String> = node.meshes.iter().map(|x| x.to_string()).collect();
+    if meshes.is_empty() {
+        writeln!(out, "{:width$}{}", "", node.name, width = depth * 2)?;
+    } else {
+        writeln!(out, "{:width$}{} [meshes: {}]", "", node.name, meshes.join(", "),
+                 width = depth * 2)?;
+    }
+    for child in &node.children {
+        print_node(out, child, depth + 1)?;
+    }
+    Ok(())
+}
+
+/// Prints the warnings and errors logged during an import.
+pub fn print_warnings<W: Write>(out: &mut W, messages: &[LogMessage]) {
+    for msg in messages {
+        let _ = writeln!(out, "{}: {}", severity_name(msg.severity), msg.text);
+    }
+}
+
+/// Returns the name shown for messages of the specified severity.
+pub fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Debug => "debug",
+        Severity::Info => "info",
+        Severity::Warn => "warning",
+        Severity::Error => "error"
+    }
+}
@@ -0,0 +1,56 @@
+//! Command-line tool to inspect, convert and compare models with assimp-rs.
+//!
+//! Built with the `cli` feature: `cargo install assimp --features cli`.
+
+extern crate assimp;
+extern crate glob;
+extern crate serde;
+extern crate serde_json;
+extern crate toml;
+
+use std::env;
+use std::process;
+
+mod args;
+mod convert;
+mod diff;
+mod info;
+
+const USAGE: &'static str = "\
+Usage: assimp-rs <command> [options]
+
+Commands:
+    info       Print the contents of a model
+    convert    Convert models to another format
+    diff       Compare two models
+    help       Print this message, or the options of a command
+
+Run 'assimp-rs help <command>' for the options of a command.
+";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next();
+    let result = match command.as_ref().map(|x| &x[..]) {
+        Some("info") => info::run(args),
+        Some("convert") => convert::run(args),
+        Some("diff") => diff::run(args),
+        Some("help") | Some("--help") | Some("-h") | None => help(args.next()),
+        Some(other) => Err(format!("unknown command '{}'\n\n{}", other, USAGE))
+    };
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn help(command: Option<String>) -> Result<(), String> {
+    match command.as_ref().map(|x| &x[..]) {
+        Some("info") => print!("{}\n{}", info::USAGE, args::POST_PROCESS_HELP),
+        Some("convert") => print!("{}\n{}", convert::USAGE, args::POST_PROCESS_HELP),
+        Some("diff") => print!("{}\n{}", diff::USAGE, args::POST_PROCESS_HELP),
+        Some(other) => return Err(format!("unknown command '{}'", other)),
+        None => print!("{}", USAGE)
+    }
+    Ok(())
+}
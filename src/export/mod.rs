@@ -1 +1,172 @@
-//! The `export` module implements functionality for exporting scenes (not yet implemented).
+//! The `export` module implements functionality for exporting scenes.
+//!
+//! # Examples
+//! ```no_run
+//! use assimp::export::Exporter;
+//! use assimp::import::Importer;
+//!
+//! let importer = Importer::new();
+//! let scene = importer.read_file("models/box.obj").unwrap();
+//!
+//! let exporter = Exporter::new("collada").unwrap();
+//! exporter.export_file(&scene, "models/box.dae").unwrap();
+//! ```
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::slice;
+
+use ffi::*;
+
+use import::extension_of;
+use log::{LogMessage, Severity, ThreadCapture};
+use scene::Scene;
+
+/// Describes one of the export formats supported by Assimp.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportFormat {
+    /// Identifier of the format, e.g. `collada` or `obj`.
+    pub id: String,
+    /// Full name of the format, e.g. `COLLADA - Digital Asset Exchange Schema`.
+    pub description: String,
+    /// Extension of the exported files, without leading dot.
+    pub extension: String
+}
+
+impl ExportFormat {
+    /// Returns all the export formats supported by Assimp.
+    pub fn all() -> Vec<ExportFormat> {
+        let count = unsafe { aiGetExportFormatCount() };
+        (0..count).filter_map(|index| {
+            let desc = unsafe { aiGetExportFormatDescription(index) };
+            if desc.is_null() {
+                None
+            } else {
+                Some(ExportFormat::from_raw(unsafe { &*desc }))
+            }
+        }).collect()
+    }
+
+    /// Returns the export format with the specified identifier.
+    pub fn from_id(id: &str) -> Option<ExportFormat> {
+        ExportFormat::all().into_iter().find(|x| x.id == id)
+    }
+
+    /// Returns the first export format writing files with the extension of the specified file
+    /// path or extension.
+    ///
+    /// Both `"models/box.obj"` and `"obj"` are accepted. Several formats may write files with
+    /// the same extension, e.g. `stl` and `stlb`; use `from_id` to choose a specific one.
+    pub fn for_file(path_or_ext: &str) -> Option<ExportFormat> {
+        let ext = extension_of(path_or_ext);
+        ExportFormat::all().into_iter().find(|x| x.extension.eq_ignore_ascii_case(ext))
+    }
+
+    fn from_raw(desc: &AiExportFormatDesc) -> ExportFormat {
+        ExportFormat {
+            id: to_string(desc.id),
+            description: to_string(desc.description),
+            extension: to_string(desc.file_extension)
+        }
+    }
+}
+
+/// A file produced by `Exporter::export_to_memory`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportBlob {
+    /// Empty for the main file. Other files, such as the material library of an OBJ file, are
+    /// named after the extension they would have if exported to a file, e.g. `mtl`.
+    pub name: String,
+    pub data: Vec<u8>
+}
+
+/// Exports scenes to one of the formats supported by Assimp.
+///
+/// Exporting doesn't modify the scene, and scenes can be exported from several threads at once.
+pub struct Exporter {
+    format: ExportFormat,
+    format_id: CString
+}
+
+impl Exporter {
+    /// Creates an exporter for the format with the specified identifier.
+    ///
+    /// Returns `None` if there is no such format, see `ExportFormat::all` for the list.
+    pub fn new(format_id: &str) -> Option<Exporter> {
+        ExportFormat::from_id(format_id).map(Exporter::with_format)
+    }
+
+    /// Creates an exporter for the specified format.
+    pub fn with_format(format: ExportFormat) -> Exporter {
+        let format_id = CString::new(format.id.clone()).unwrap();
+        Exporter { format: format, format_id: format_id }
+    }
+
+    /// Returns the format written by the exporter.
+    pub fn format(&self) -> &ExportFormat {
+        &self.format
+    }
+
+    /// Exports a scene to the specified file.
+    ///
+    /// Formats storing materials or textures in separate files write them next to it. If the
+    /// export fails, the error is the last one logged by Assimp while exporting.
+    pub fn export_file(&self, scene: &Scene, file: &str) -> Result<(), String> {
+        let cstr = CString::new(file).map_err(|_| format!("invalid file name '{}'", file))?;
+        let capture = ThreadCapture::start();
+        let result = unsafe {
+            aiExportScene(scene.to_raw(), self.format_id.as_ptr(), cstr.as_ptr(),
+                          AiPostProcessSteps::empty())
+        };
+        let messages = capture.finish();
+        if result == AiReturn::Success {
+            Ok(())
+        } else {
+            Err(last_error(messages)
+                .unwrap_or_else(|| format!("failed to export '{}' as {}", file, self.format.id)))
+        }
+    }
+
+    /// Exports a scene to memory.
+    ///
+    /// Returns the main file first, followed by the other files written by the format if any.
+    pub fn export_to_memory(&self, scene: &Scene) -> Result<Vec<ExportBlob>, String> {
+        let capture = ThreadCapture::start();
+        let head = unsafe {
+            aiExportSceneToBlob(scene.to_raw(), self.format_id.as_ptr(), AiPostProcessSteps::empty())
+        };
+        let messages = capture.finish();
+        if head.is_null() {
+            return Err(last_error(messages)
+                .unwrap_or_else(|| format!("failed to export as {}", self.format.id)));
+        }
+
+        let mut blobs = Vec::new();
+        let mut blob = head;
+        while !blob.is_null() {
+            let raw = unsafe { &*blob };
+            let data = if raw.data.is_null() {
+                Vec::new()
+            } else {
+                unsafe { slice::from_raw_parts(raw.data as *const u8, raw.size as usize) }.to_vec()
+            };
+            blobs.push(ExportBlob { name: raw.name.as_ref().to_owned(), data: data });
+            blob = raw.next;
+        }
+        unsafe { aiReleaseExportBlob(head) };
+        Ok(blobs)
+    }
+}
+
+// Assimp's C API only reports import errors, export errors are only available from the log.
+fn last_error(messages: Vec<LogMessage>) -> Option<String> {
+    messages.into_iter().rev().find(|x| x.severity == Severity::Error).map(|x| x.text)
+}
+
+fn to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned()
+    }
+}
@@ -0,0 +1,223 @@
+//! Parallel import of many files.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::LogMessage;
+use scene::OwnedScene;
+
+use super::{ImportError, ImportSettings, Importer};
+
+/// Options of the batch import functions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchOptions {
+    /// Maximum number of files imported at once. Default: number of CPUs
+    pub concurrency: usize
+}
+
+impl Default for BatchOptions {
+    fn default() -> BatchOptions {
+        BatchOptions {
+            concurrency: thread::available_parallelism().map_or(1, |x| x.get())
+        }
+    }
+}
+
+/// Progress of a batch import, reported after each file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// Index of the file which was just imported.
+    pub index: usize,
+    /// Whether the file was imported successfully.
+    pub success: bool,
+    /// Number of files imported so far, including failed ones.
+    pub completed: usize,
+    /// Number of files failed so far.
+    pub failed: usize,
+    /// Number of files in the batch.
+    pub total: usize
+}
+
+/// Outcome of the import of one file of a batch.
+///
+/// See `import_batch_each` and `import_batch_process`.
+#[derive(Debug)]
+pub struct BatchResult {
+    /// The imported scene, or the error logged by its import.
+    pub scene: Result<OwnedScene, ImportError>,
+    /// Warnings and errors logged while importing the file.
+    pub messages: Vec<LogMessage>,
+    /// Time taken to import the file.
+    pub duration: Duration
+}
+
+/// Imports files in parallel with the same settings.
+///
+/// Equivalent to `import_batch_with_progress` with default options and no progress reporting.
+pub fn import_batch<P: AsRef<str> + Sync>(paths: &[P], settings: &ImportSettings)
+    -> Vec<Result<OwnedScene, ImportError>>
+{
+    import_batch_with_progress(paths, settings, &BatchOptions::default(), |_| ())
+}
+
+/// Imports files in parallel with the same settings, reporting progress after each file.
+///
+/// The results are returned in the order of `paths`. `progress` is called in the order files
+/// complete, which may differ from their order in `paths`. See `import_batch_each` for how files
+/// are imported.
+///
+/// Imported scenes are kept until all files are done. Use `import_batch_each` to process each
+/// scene as soon as it's imported and drop it afterwards.
+///
+/// # Examples
+/// ```
+/// use assimp::import::{import_batch_with_progress, BatchOptions, ImportSettings};
+///
+/// let paths = ["examples/box.obj", "examples/spider.obj"];
+/// let options = BatchOptions { concurrency: 2 };
+/// let results = import_batch_with_progress(&paths, &ImportSettings::default(), &options, |x| {
+///     println!("[{}/{}] {}", x.completed, x.total, paths[x.index]);
+/// });
+/// assert!(results.iter().all(|x| x.is_ok()));
+/// ```
+pub fn import_batch_with_progress<P, F>(paths: &[P], settings: &ImportSettings, options: &BatchOptions,
+                                        mut progress: F) -> Vec<Result<OwnedScene, ImportError>>
+    where P: AsRef<str> + Sync, F: FnMut(&BatchProgress)
+{
+    let mut results: Vec<Option<Result<OwnedScene, ImportError>>> =
+        (0..paths.len()).map(|_| None).collect();
+    import_batch_each(paths, settings, options, |x, result| {
+        results[x.index] = Some(result.scene);
+        progress(x);
+    });
+    // Panics in workers are propagated, so every file has a result
+    results.into_iter().map(|x| x.unwrap()).collect()
+}
+
+/// Imports files in parallel with the same settings, passing each result to `each` as soon as
+/// the file is imported.
+///
+/// `each` is called on the calling thread in the order files complete, which may differ from
+/// their order in `paths`. While it runs, the other workers keep importing their files.
+///
+/// Scenes are owned by `each`, and each worker waits for it to take its scene before importing
+/// the next file, so if `each` drops them at most one scene per worker plus the one passed to
+/// `each` are alive at once. See `import_batch_process` for how files are imported.
+///
+/// # Examples
+/// ```
+/// use assimp::import::{import_batch_each, BatchOptions, ImportSettings};
+///
+/// let paths = ["examples/box.obj", "examples/spider.obj"];
+/// import_batch_each(&paths, &ImportSettings::default(), &BatchOptions::default(), |x, result| {
+///     let scene = result.scene.unwrap();
+///     println!("{}: {} meshes", paths[x.index], scene.num_meshes());
+/// });
+/// ```
+pub fn import_batch_each<P, F>(paths: &[P], settings: &ImportSettings, options: &BatchOptions,
+                               each: F)
+    where P: AsRef<str> + Sync, F: FnMut(&BatchProgress, BatchResult)
+{
+    import_batch_process(paths, settings, options, |_, result| result, each)
+}
+
+/// Imports files in parallel with the same settings, processing each result on the thread
+/// which imported it.
+///
+/// Files are imported on up to `options.concurrency` threads, each with its own `Importer`
+/// configured from `settings`. Once a file is imported, the same thread calls `process` with
+/// its index in `paths` and the result, then passes the value returned by `process` to `each`.
+/// `each` is called on the calling thread in the order files complete, which may differ from
+/// their order in `paths`. `BatchProgress::success` tells whether the import succeeded,
+/// whatever `process` did with the scene.
+///
+/// The concurrency limit bounds the memory used by imports in progress, which for complex
+/// files is often several times the size of the resulting scene. If `process` drops the scene,
+/// at most one scene per worker is alive at once.
+///
+/// # Thread safety
+/// Assimp imports running on different threads are independent, but some of Assimp's state
+/// is shared by the whole process:
+///
+/// - The error string returned by `aiGetErrorString` is overwritten by every failed import.
+///   Batch imports never read it: the error of each file is the last error logged by its own
+///   import, or a fixed message if it logged none, as with `Importer::read_file_with_log`.
+/// - Assimp has a single logger. Capturing the messages of each import attaches a dispatch
+///   stream to it the first time, and leaves it attached afterwards. Messages are attributed
+///   to the thread logging them, so each result only holds the messages of its own file, but
+///   streams attached with `LogStream` or `log::forward_to_log` receive the messages of all
+///   files interleaved.
+/// - Verbose logging is global, and is left unchanged.
+///
+/// # Examples
+/// ```
+/// use assimp::import::{import_batch_process, BatchOptions, ImportSettings};
+///
+/// let paths = ["examples/box.obj", "examples/spider.obj"];
+/// let options = BatchOptions::default();
+/// import_batch_process(&paths, &ImportSettings::default(), &options, |_, result| {
+///     // Runs on the worker, so the scene is dropped there
+///     result.scene.map(|scene| scene.num_meshes())
+/// }, |x, meshes| {
+///     println!("{}: {} meshes", paths[x.index], meshes.unwrap());
+/// });
+/// ```
+pub fn import_batch_process<P, T, W, F>(paths: &[P], settings: &ImportSettings,
+                                        options: &BatchOptions, process: W, mut each: F)
+    where P: AsRef<str> + Sync, T: Send, W: Fn(usize, BatchResult) -> T + Sync,
+          F: FnMut(&BatchProgress, T)
+{
+    let total = paths.len();
+    let threads = options.concurrency.max(1).min(total);
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        // Without a buffer, so that results don't pile up while `each` is busy
+        let (sender, receiver) = mpsc::sync_channel(0);
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let next = &next;
+            let process = &process;
+            scope.spawn(move || {
+                // Importers can't be shared between threads, each worker has its own
+                let importer = Importer::with_settings(settings);
+                loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    if index >= total {
+                        break;
+                    }
+                    let start = Instant::now();
+                    let (scene, messages) = importer.read_file_with_log(paths[index].as_ref());
+                    let result = BatchResult {
+                        scene: scene.map(OwnedScene::new).map_err(ImportError::Failed),
+                        messages: messages,
+                        duration: start.elapsed()
+                    };
+                    let success = result.scene.is_ok();
+                    let output = process(index, result);
+                    if sender.send((index, success, output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut failed = 0;
+        for (completed, (index, success, output)) in receiver.iter().enumerate() {
+            if !success {
+                failed += 1;
+            }
+            let progress = BatchProgress {
+                index: index,
+                success: success,
+                completed: completed + 1,
+                failed: failed,
+                total: total
+            };
+            each(&progress, output);
+        }
+    });
+}
@@ -0,0 +1,324 @@
+//! Typed keys for importer properties.
+
+use ffi::config::*;
+
+/// Keys of the properties understood by Assimp's importers, post-processing steps and exporters.
+///
+/// These correspond to the `AI_CONFIG_*` constants in Assimp's `config.h`, and can be passed to
+/// `Importer::set_property_int` and friends to set properties that have no dedicated method on
+/// `Importer`. Keys that aren't listed here can be passed as plain strings instead.
+///
+/// Some keys are only understood by newer versions of Assimp than the one bundled with
+/// assimp-sys. Older versions silently ignore unknown properties.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConfigKey {
+    /// Enables time measurements. See `Importer::measure_time`.
+    GlobMeasureTime,
+    /// Hint to favour speed against import quality. See `Importer::favour_speed`.
+    FavourSpeed,
+    /// Maximum smoothing angle for `calc_tangent_space`.
+    PpCtMaxSmoothingAngle,
+    /// Source UV channel for `calc_tangent_space`.
+    PpCtTextureChannelIndex,
+    /// Maximum smoothing angle for `generate_normals`.
+    PpGsnMaxSmoothingAngle,
+    /// Materials to keep in `remove_redudant_materials`.
+    PpRrmExcludeList,
+    /// Keep the scene hierarchy in `pre_transform_vertices`.
+    PpPtvKeepHierarchy,
+    /// Normalize vertices in `pre_transform_vertices`.
+    PpPtvNormalize,
+    /// Apply a root transformation in `pre_transform_vertices`.
+    PpPtvAddRootTransformation,
+    /// Root transformation matrix for `pre_transform_vertices`.
+    PpPtvRootTransformation,
+    /// Remove degenerate primitives in `find_degenerates`.
+    PpFdRemove,
+    /// Nodes to keep in `optimize_graph`.
+    PpOgExcludeList,
+    /// Maximum number of triangles per mesh for `split_large_meshes`.
+    PpSlmTriangleLimit,
+    /// Maximum number of vertices per mesh for `split_large_meshes`.
+    PpSlmVertexLimit,
+    /// Maximum number of bone weights per vertex for `limit_bone_weights`.
+    PpLbwMaxWeights,
+    /// Bone threshold for `debone`.
+    PpDbThreshold,
+    /// Only remove bones in `debone` if all of them qualify.
+    PpDbAllOrNone,
+    /// Vertex cache size for `improve_cache_locality`.
+    PpIclPtcacheSize,
+    /// Components removed by `remove_component`.
+    PpRvcFlags,
+    /// Primitive types removed by `sort_by_primitive_type`.
+    PpSbpRemove,
+    /// Animation accuracy for `find_invalid_data`.
+    PpFidAnimAccuracy,
+    /// UV transformations evaluated by `transform_uv_coords`.
+    PpTuvEvaluate,
+    /// Maximum number of bones per mesh for `split_by_bone_count`.
+    PpSbbcMaxBones,
+    /// See `Importer::import_no_skeleton_meshes`.
+    ImportNoSkeletonMeshes,
+    /// See `Importer::import_mdl_colormap`.
+    ImportMdlColormap,
+    /// See `Importer::fbx_read_all_geometry_layers`.
+    ImportFbxReadAllGeometryLayers,
+    /// See `Importer::fbx_read_all_materials`.
+    ImportFbxReadAllMaterials,
+    /// See `Importer::fbx_read_materials`.
+    ImportFbxReadMaterials,
+    /// See `Importer::fbx_read_textures`.
+    ImportFbxReadTextures,
+    /// See `Importer::fbx_read_cameras`.
+    ImportFbxReadCameras,
+    /// See `Importer::fbx_read_lights`.
+    ImportFbxReadLights,
+    /// See `Importer::fbx_read_animations`.
+    ImportFbxReadAnimations,
+    /// See `Importer::fbx_strict_mode`.
+    ImportFbxStrictMode,
+    /// See `Importer::fbx_preserve_pivots`.
+    ImportFbxPreservePivots,
+    /// See `Importer::fbx_optimize_empty_animation_curves`.
+    ImportFbxOptimizeEmptyAnimationCurves,
+    /// See `Importer::global_keyframe`.
+    ImportGlobalKeyframe,
+    /// See `Importer::md3_keyframe`.
+    ImportMd3Keyframe,
+    /// See `Importer::md2_keyframe`.
+    ImportMd2Keyframe,
+    /// See `Importer::mdl_keyframe`.
+    ImportMdlKeyframe,
+    /// See `Importer::mdc_keyframe`.
+    ImportMdcKeyframe,
+    /// See `Importer::smd_keyframe`.
+    ImportSmdKeyframe,
+    /// See `Importer::unreal_keyframe`.
+    ImportUnrealKeyframe,
+    /// See `Importer::ac_separate_bf_cull`.
+    ImportAcSeparateBfcull,
+    /// See `Importer::ac_eval_subdivision`.
+    ImportAcEvalSubdivision,
+    /// See `Importer::unreal_handle_flags`.
+    UnrealHandleFlags,
+    /// See `Importer::ter_make_uvs`.
+    ImportTerMakeUvs,
+    /// See `Importer::ase_reconstruct_normals`.
+    ImportAseReconstructNormals,
+    /// See `Importer::md3_handle_multipart`.
+    ImportMd3HandleMultipart,
+    /// See `Importer::md3_skin_name`.
+    ImportMd3SkinName,
+    /// See `Importer::md3_shader_src`.
+    ImportMd3ShaderSrc,
+    /// See `Importer::lwo_one_layer_only_str`.
+    ImportLwoOneLayerOnly,
+    /// See `Importer::md5_no_anim_autoload`.
+    ImportMd5NoAnimAutoload,
+    /// See `Importer::lws_anim_start`.
+    ImportLwsAnimStart,
+    /// See `Importer::lws_anim_end`.
+    ImportLwsAnimEnd,
+    /// See `Importer::irr_anim_fps`.
+    ImportIrrAnimFps,
+    /// See `Importer::ogre_material_file`.
+    ImportOgreMaterialFile,
+    /// See `Importer::ogre_texture_type_from_filename`.
+    ImportOgreTexturetypeFromFilename,
+    /// See `Importer::ifc_skip_space_representations`.
+    ImportIfcSkipSpaceRepresentations,
+    /// See `Importer::ifc_skip_curve_representations`.
+    ImportIfcSkipCurveRepresentations,
+    /// See `Importer::ifc_custom_triangulation`.
+    ImportIfcCustomTriangulation,
+    /// See `Importer::collada_ignore_up_direction`.
+    ImportColladaIgnoreUpDirection,
+    /// Scale factor for `global_scale`. Requires Assimp 4.1 or later.
+    GlobalScaleFactor,
+    /// Application scale applied on top of `GlobalScaleFactor`. Requires Assimp 5.0 or later.
+    AppScaleFactor,
+    /// Epsilon used when checking for identity matrices. Requires Assimp 4.1 or later.
+    CheckIdentityMatrixEpsilon,
+    /// Treat faces with zero area as degenerate in `find_degenerates`.
+    /// Requires Assimp 4.1 or later.
+    PpFdCheckArea,
+    /// Read bone weights from FBX files. Requires Assimp 5.0 or later.
+    ImportFbxReadWeights,
+    /// Use the legacy naming scheme for embedded FBX textures. Requires Assimp 4.1 or later.
+    ImportFbxEmbeddedTexturesLegacyNaming,
+    /// Remove bones without any vertex weights. Requires Assimp 5.0 or later.
+    ImportRemoveEmptyBones,
+    /// Use Collada names instead of IDs for nodes. Requires Assimp 4.1 or later.
+    ImportColladaUseColladaNames,
+    /// Ignore texture coordinates in `find_invalid_data`. Requires Assimp 4.1 or later.
+    PpFidIgnoreTextureCoords,
+    /// Schema document provider used by the X3D importer. Takes a pointer, so it can't be
+    /// set through `Importer`. Requires Assimp 4.1 or later.
+    ImportSchemaDocumentProvider,
+    /// Convert FBX files to meters. Requires Assimp 5.1 or later.
+    FbxConvertToM,
+    /// Keep FBX skeletons in a separate bone container. Requires Assimp 5.2 or later.
+    FbxUseSkeletonBoneContainer,
+    /// Search for embedded FBX textures by their file name. Requires Assimp 4.1 or later.
+    ImportFbxSearchEmbeddedTextures,
+    /// Ignore the up direction of FBX files. Requires Assimp 5.3 or later.
+    ImportFbxIgnoreUpDirection,
+    /// Read animations of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadAnimations,
+    /// Read animation events of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadAnimationEvents,
+    /// Read blend controllers of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadBlendControllers,
+    /// Read sequence transitions of Half-Life 1 MDL files. Requires Assimp 5.0 or
+    /// later.
+    ImportMdlHl1ReadSequenceTransitions,
+    /// Read attachments of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadAttachments,
+    /// Read bone controllers of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadBoneControllers,
+    /// Read hitboxes of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadHitboxes,
+    /// Read global info of Half-Life 1 MDL files. Requires Assimp 5.0 or later.
+    ImportMdlHl1ReadMiscGlobalInfo,
+    /// List of external animation files loaded by the SMD importer. Requires Assimp 4.1 or
+    /// later.
+    ImportSmdLoadAnimationList,
+    /// Load the shaders of MD3 files. Requires Assimp 5.0 or later.
+    ImportMd3LoadShaders,
+    /// Use the Android JNI asset manager to open files. Requires Assimp 4.1 or later.
+    AndroidJniAssimpManagerSupport,
+    /// Smoothing angle of IFC curves and surfaces. Requires Assimp 4.1 or later.
+    ImportIfcSmoothingAngle,
+    /// Tessellation of IFC cylinders. Requires Assimp 4.1 or later.
+    ImportIfcCylindricalTessellation,
+    /// Ignore the unit size of Collada files. Requires Assimp 5.0 or later.
+    ImportColladaIgnoreUnitSize,
+    /// Read and write the glTF specular-glossiness extension. Requires Assimp 5.1 or later.
+    UseGltfPbrSpecularGlossiness,
+    /// Write 64-bit X files. Requires Assimp 4.1 or later.
+    ExportXfile64Bit,
+    /// Export meshes without faces as point clouds. Requires Assimp 5.0 or later.
+    ExportPointClouds,
+    /// Name of the main blob of in-memory exports. Requires Assimp 4.1 or later.
+    ExportBlobName,
+    /// Write FBX transparency factors as opacity. Requires Assimp 5.1 or later.
+    ExportFbxTransparencyFactorReferToOpacity,
+    /// Write more than four bone weights per vertex to glTF. Requires Assimp 5.2 or
+    /// later.
+    ExportGltfUnlimitedSkinningBonesPerVertex
+}
+
+impl ConfigKey {
+    /// Returns the key string passed to Assimp.
+    pub fn as_str(&self) -> &'static str {
+        use self::ConfigKey::*;
+
+        match *self {
+            GlobMeasureTime => GLOB_MEASURE_TIME,
+            FavourSpeed => FAVOUR_SPEED,
+            PpCtMaxSmoothingAngle => PP_CT_MAX_SMOOTHING_ANGLE,
+            PpCtTextureChannelIndex => PP_CT_TEXTURE_CHANNEL_INDEX,
+            PpGsnMaxSmoothingAngle => PP_GSN_MAX_SMOOTHING_ANGLE,
+            PpRrmExcludeList => PP_RRM_EXCLUDE_LIST,
+            PpPtvKeepHierarchy => PP_PTV_KEEP_HIERARCHY,
+            PpPtvNormalize => PP_PTV_NORMALIZE,
+            PpPtvAddRootTransformation => PP_PTV_ADD_ROOT_TRANSFORMATION,
+            PpPtvRootTransformation => PP_PTV_ROOT_TRANSFORMATION,
+            PpFdRemove => PP_FD_REMOVE,
+            PpOgExcludeList => PP_OG_EXCLUDE_LIST,
+            PpSlmTriangleLimit => PP_SLM_TRIANGLE_LIMIT,
+            PpSlmVertexLimit => PP_SLM_VERTEX_LIMIT,
+            PpLbwMaxWeights => PP_LBW_MAX_WEIGHTS,
+            PpDbThreshold => PP_DB_THRESHOLD,
+            PpDbAllOrNone => PP_DB_ALL_OR_NONE,
+            PpIclPtcacheSize => PP_ICL_PTCACHE_SIZE,
+            PpRvcFlags => PP_RVC_FLAGS,
+            PpSbpRemove => PP_SBP_REMOVE,
+            PpFidAnimAccuracy => PP_FID_ANIM_ACCURACY,
+            PpTuvEvaluate => PP_TUV_EVALUATE,
+            PpSbbcMaxBones => PP_SBBC_MAX_BONES,
+            ImportNoSkeletonMeshes => IMPORT_NO_SKELETON_MESHES,
+            ImportMdlColormap => IMPORT_MDL_COLORMAP,
+            ImportFbxReadAllGeometryLayers => IMPORT_FBX_READ_ALL_GEOMETRY_LAYERS,
+            ImportFbxReadAllMaterials => IMPORT_FBX_READ_ALL_MATERIALS,
+            ImportFbxReadMaterials => IMPORT_FBX_READ_MATERIALS,
+            ImportFbxReadTextures => IMPORT_FBX_READ_TEXTURES,
+            ImportFbxReadCameras => IMPORT_FBX_READ_CAMERAS,
+            ImportFbxReadLights => IMPORT_FBX_READ_LIGHTS,
+            ImportFbxReadAnimations => IMPORT_FBX_READ_ANIMATIONS,
+            ImportFbxStrictMode => IMPORT_FBX_STRICT_MODE,
+            ImportFbxPreservePivots => IMPORT_FBX_PRESERVE_PIVOTS,
+            ImportFbxOptimizeEmptyAnimationCurves => IMPORT_FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES,
+            ImportGlobalKeyframe => IMPORT_GLOBAL_KEYFRAME,
+            ImportMd3Keyframe => IMPORT_MD3_KEYFRAME,
+            ImportMd2Keyframe => IMPORT_MD2_KEYFRAME,
+            ImportMdlKeyframe => IMPORT_MDL_KEYFRAME,
+            ImportMdcKeyframe => IMPORT_MDC_KEYFRAME,
+            ImportSmdKeyframe => IMPORT_SMD_KEYFRAME,
+            ImportUnrealKeyframe => IMPORT_UNREAL_KEYFRAME,
+            ImportAcSeparateBfcull => IMPORT_AC_SEPARATE_BFCULL,
+            ImportAcEvalSubdivision => IMPORT_AC_EVAL_SUBDIVISION,
+            UnrealHandleFlags => UNREAL_HANDLE_FLAGS,
+            ImportTerMakeUvs => IMPORT_TER_MAKE_UVS,
+            ImportAseReconstructNormals => IMPORT_ASE_RECONSTRUCT_NORMALS,
+            ImportMd3HandleMultipart => IMPORT_MD3_HANDLE_MULTIPART,
+            ImportMd3SkinName => IMPORT_MD3_SKIN_NAME,
+            ImportMd3ShaderSrc => IMPORT_MD3_SHADER_SRC,
+            ImportLwoOneLayerOnly => IMPORT_LWO_ONE_LAYER_ONLY,
+            ImportMd5NoAnimAutoload => IMPORT_MD5_NO_ANIM_AUTOLOAD,
+            ImportLwsAnimStart => IMPORT_LWS_ANIM_START,
+            ImportLwsAnimEnd => IMPORT_LWS_ANIM_END,
+            ImportIrrAnimFps => IMPORT_IRR_ANIM_FPS,
+            ImportOgreMaterialFile => IMPORT_OGRE_MATERIAL_FILE,
+            ImportOgreTexturetypeFromFilename => IMPORT_OGRE_TEXTURETYPE_FROM_FILENAME,
+            ImportIfcSkipSpaceRepresentations => IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS,
+            ImportIfcSkipCurveRepresentations => IMPORT_IFC_SKIP_CURVE_REPRESENTATIONS,
+            ImportIfcCustomTriangulation => IMPORT_IFC_CUSTOM_TRIANGULATION,
+            ImportColladaIgnoreUpDirection => IMPORT_COLLADA_IGNORE_UP_DIRECTION,
+            GlobalScaleFactor => "GLOBAL_SCALE_FACTOR",
+            AppScaleFactor => "APP_SCALE_FACTOR",
+            CheckIdentityMatrixEpsilon => "CHECK_IDENTITY_MATRIX_EPSILON",
+            PpFdCheckArea => "PP_FD_CHECKAREA",
+            ImportFbxReadWeights => "IMPORT_FBX_READ_WEIGHTS",
+            ImportFbxEmbeddedTexturesLegacyNaming =>
+                "AI_CONFIG_IMPORT_FBX_EMBEDDED_TEXTURES_LEGACY_NAMING",
+            ImportRemoveEmptyBones => "AI_CONFIG_IMPORT_REMOVE_EMPTY_BONES",
+            ImportColladaUseColladaNames => "IMPORT_COLLADA_USE_COLLADA_NAMES",
+            PpFidIgnoreTextureCoords => "PP_FID_IGNORE_TEXTURECOORDS",
+            ImportSchemaDocumentProvider => "IMPORT_SCHEMA_DOCUMENT_PROVIDER",
+            FbxConvertToM => "AI_CONFIG_FBX_CONVERT_TO_M",
+            FbxUseSkeletonBoneContainer => "AI_CONFIG_FBX_USE_SKELETON_BONE_CONTAINER",
+            ImportFbxSearchEmbeddedTextures => "IMPORT_FBX_SEARCH_EMBEDDED_TEXTURES",
+            ImportFbxIgnoreUpDirection => "AI_CONFIG_IMPORT_FBX_IGNORE_UP_DIRECTION",
+            ImportMdlHl1ReadAnimations => "IMPORT_MDL_HL1_READ_ANIMATIONS",
+            ImportMdlHl1ReadAnimationEvents => "IMPORT_MDL_HL1_READ_ANIMATION_EVENTS",
+            ImportMdlHl1ReadBlendControllers => "IMPORT_MDL_HL1_READ_BLEND_CONTROLLERS",
+            ImportMdlHl1ReadSequenceTransitions => "IMPORT_MDL_HL1_READ_SEQUENCE_TRANSITIONS",
+            ImportMdlHl1ReadAttachments => "IMPORT_MDL_HL1_READ_ATTACHMENTS",
+            ImportMdlHl1ReadBoneControllers => "IMPORT_MDL_HL1_READ_BONE_CONTROLLERS",
+            ImportMdlHl1ReadHitboxes => "IMPORT_MDL_HL1_READ_HITBOXES",
+            ImportMdlHl1ReadMiscGlobalInfo => "IMPORT_MDL_HL1_READ_MISC_GLOBAL_INFO",
+            ImportSmdLoadAnimationList => "IMPORT_SMD_LOAD_ANIMATION_LIST",
+            ImportMd3LoadShaders => "IMPORT_MD3_LOAD_SHADERS",
+            AndroidJniAssimpManagerSupport => "AI_CONFIG_ANDROID_JNI_ASSIMP_MANAGER_SUPPORT",
+            ImportIfcSmoothingAngle => "IMPORT_IFC_SMOOTHING_ANGLE",
+            ImportIfcCylindricalTessellation => "IMPORT_IFC_CYLINDRICAL_TESSELLATION",
+            ImportColladaIgnoreUnitSize => "IMPORT_COLLADA_IGNORE_UNIT_SIZE",
+            UseGltfPbrSpecularGlossiness => "USE_GLTF_PBR_SPECULAR_GLOSSINESS",
+            ExportXfile64Bit => "EXPORT_XFILE_64BIT",
+            ExportPointClouds => "EXPORT_POINT_CLOUDS",
+            ExportBlobName => "EXPORT_BLOB_NAME",
+            ExportFbxTransparencyFactorReferToOpacity =>
+                "EXPORT_FBX_TRANSPARENCY_FACTOR_REFER_TO_OPACITY",
+            // The space is part of the key in Assimp
+            ExportGltfUnlimitedSkinningBonesPerVertex => "USE_UNLIMITED_BONES_PER VERTEX"
+        }
+    }
+}
+
+impl AsRef<str> for ConfigKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
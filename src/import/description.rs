@@ -0,0 +1,112 @@
+//! Descriptions of the file formats supported by Assimp's importers.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
+use std::vec;
+
+use ffi::*;
+
+/// Describes one of the importers built into Assimp.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImporterDescription {
+    /// Full name of the importer, e.g. `Wavefront Object Importer`.
+    pub name: String,
+    /// Original author of the importer, may be empty.
+    pub author: String,
+    /// Current maintainer of the importer, may be empty.
+    pub maintainer: String,
+    /// Implementation comments, e.g. unimplemented features.
+    pub comments: String,
+    /// Whether the format has a textual encoding.
+    pub supports_text: bool,
+    /// Whether the format has a binary encoding.
+    pub supports_binary: bool,
+    /// Whether the format has a compressed encoding.
+    pub supports_compressed: bool,
+    /// Whether the importer only supports a subset of the format.
+    pub limited_support: bool,
+    /// Whether the importer is highly experimental and should be used with care.
+    pub experimental: bool,
+    /// Minimum (major, minor) version of the format supported, `(0, 0)` if unrestricted.
+    pub min_version: (u32, u32),
+    /// Maximum (major, minor) version of the format supported, `(0, 0)` if unrestricted.
+    pub max_version: (u32, u32),
+    /// File extensions handled by the importer, without leading dots.
+    pub extensions: Vec<String>
+}
+
+impl ImporterDescription {
+    /// Returns the description of the importer handling the specified extension or file path.
+    ///
+    /// Returns `None` if no importer handles the file.
+    pub fn for_file(path_or_ext: &str) -> Option<ImporterDescription> {
+        // `aiGetImporterDesc` isn't declared `extern "C"` by Assimp 4.0, so it can't be linked
+        let ext = extension_of(path_or_ext);
+        ImporterDescriptionIter::new()
+            .find(|desc| desc.extensions.iter().any(|x| extension_of(x).eq_ignore_ascii_case(ext)))
+    }
+
+    fn from_raw(desc: &AiImporterDesc) -> ImporterDescription {
+        let extensions = to_string(desc.file_extensions);
+        ImporterDescription {
+            name: to_string(desc.name),
+            author: to_string(desc.author),
+            maintainer: to_string(desc.maintainer),
+            comments: to_string(desc.comments),
+            supports_text: desc.flags.contains(AIIMPORTERFLAG_SUPPORT_TEXT_FLAVOUR),
+            supports_binary: desc.flags.contains(AIIMPORTERFLAG_SUPPORT_BINARY_FLAVOUR),
+            supports_compressed: desc.flags.contains(AIIMPORTERFLAG_SUPPORT_COMPRESSED_FLAVOUR),
+            limited_support: desc.flags.contains(AIIMPORTERFLAG_LIMITED_SUPPORT),
+            experimental: desc.flags.contains(AIIMPORTERFLAG_EXPERIMENTAL),
+            min_version: (desc.min_major, desc.min_minor),
+            max_version: (desc.max_major, desc.max_minor),
+            extensions: extensions.split_whitespace().map(|x| x.to_owned()).collect()
+        }
+    }
+}
+
+/// Iterator over the descriptions of all importers.
+///
+/// See `Importer::importer_descriptions`.
+pub struct ImporterDescriptionIter(vec::IntoIter<ImporterDescription>);
+
+#[doc(hidden)]
+impl ImporterDescriptionIter {
+    pub fn new() -> ImporterDescriptionIter {
+        let count = unsafe { aiGetImportFormatCount() };
+        let descriptions: Vec<ImporterDescription> = (0..count)
+            .map(|i| unsafe { aiGetImportFormatDescription(i) })
+            .filter(|x| !x.is_null())
+            .map(|x| ImporterDescription::from_raw(unsafe { &*x }))
+            .collect();
+        ImporterDescriptionIter(descriptions.into_iter())
+    }
+}
+
+impl Iterator for ImporterDescriptionIter {
+    type Item = ImporterDescription;
+    fn next(&mut self) -> Option<ImporterDescription> {
+        self.0.next()
+    }
+}
+
+impl ExactSizeIterator for ImporterDescriptionIter {
+    fn len(&self) -> usize { self.0.len() }
+}
+
+/// Returns the extension of a file path, or the argument itself if it has no extension.
+pub fn extension_of(path_or_ext: &str) -> &str {
+    Path::new(path_or_ext).extension()
+        .and_then(|x| x.to_str())
+        .unwrap_or(path_or_ext)
+        .trim_start_matches(|c| c == '*' || c == '.')
+}
+
+fn to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned()
+    }
+}
@@ -0,0 +1,24 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by imports running on other threads.
+///
+/// See `Importer::read_file_async` and `import_batch`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportError {
+    /// Assimp failed to import the file. Contains the error logged by the import.
+    Failed(String),
+    /// The import was cancelled before it completed.
+    Cancelled
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ImportError::Failed(ref error) => f.write_str(error),
+            ImportError::Cancelled => f.write_str("import cancelled")
+        }
+    }
+}
+
+impl Error for ImportError {}
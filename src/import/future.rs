@@ -0,0 +1,166 @@
+//! Asynchronous imports, enabled by the `async` feature.
+
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use scene::OwnedScene;
+
+use super::{ImportError, ImportSettings, Importer, RawProperty};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+// Threads running asynchronous imports, started by the first call to `read_file_async`.
+static POOL: OnceLock<Mutex<Sender<Job>>> = OnceLock::new();
+
+fn spawn_job(job: Job) {
+    let sender = POOL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let threads = thread::available_parallelism().map_or(1, |x| x.get());
+        for i in 0..threads {
+            let receiver = receiver.clone();
+            thread::Builder::new()
+                .name(format!("assimp-import-{}", i))
+                .spawn(move || run_worker(&receiver))
+                .expect("failed to start import thread");
+        }
+        Mutex::new(sender)
+    });
+    // The workers never exit, so sending can't fail
+    let _ = sender.lock().unwrap().send(job);
+}
+
+fn run_worker(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = match receiver.lock().unwrap().recv() {
+            Ok(job) => job,
+            Err(_) => return
+        };
+        job();
+    }
+}
+
+struct State {
+    result: Option<Result<OwnedScene, ImportError>>,
+    waker: Option<Waker>,
+    cancelled: bool
+}
+
+/// Future of an import running on a background thread.
+///
+/// Returned by `Importer::read_file_async`. Dropping the future cancels the import.
+pub struct ReadFileFuture {
+    state: Arc<Mutex<State>>
+}
+
+impl ReadFileFuture {
+    /// Cancels the import. The future then completes with `ImportError::Cancelled`.
+    ///
+    /// An import which hasn't started yet is skipped. The C API of Assimp doesn't expose its
+    /// progress handler, so an import which is already running can't be interrupted: it runs to
+    /// completion on its thread, and the scene is released once it's done.
+    pub fn cancel(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.cancelled = true;
+        state.result = None;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Future for ReadFileFuture {
+    type Output = Result<OwnedScene, ImportError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if state.cancelled {
+            return Poll::Ready(Err(ImportError::Cancelled));
+        }
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for ReadFileFuture {
+    fn drop(&mut self) {
+        let mut state = self.state.lock().unwrap();
+        state.cancelled = true;
+        state.result = None;
+    }
+}
+
+impl Importer {
+    /// Load a scene from the specified file on a background thread.
+    ///
+    /// The blocking Assimp call runs on a pool of worker threads shared by all importers, with
+    /// one thread per CPU, so awaiting the returned future doesn't block the executor. The future
+    /// can be cancelled with `ReadFileFuture::cancel` or by dropping it.
+    ///
+    /// The file is imported with a copy of `settings` and of the properties set with
+    /// `set_property_int` and similar methods, so the importer can be changed or dropped while
+    /// the import is running.
+    ///
+    /// As with `read_file_with_log`, the error is the one logged by this import, even if other
+    /// imports fail at the same time.
+    ///
+    /// Requires the `async` feature.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use assimp::import::Importer;
+    ///
+    /// let importer = Importer::new();
+    /// let future = importer.read_file_async("examples/box.obj");
+    ///
+    /// // In an async context:
+    /// // let scene = future.await?;
+    /// # drop(future);
+    /// ```
+    pub fn read_file_async(&self, file: &str) -> ReadFileFuture {
+        let state = Arc::new(Mutex::new(State { result: None, waker: None, cancelled: false }));
+        let job_state = state.clone();
+        let settings = self.settings().clone();
+        let properties = self.raw_properties.clone();
+        let file = file.to_owned();
+        spawn_job(Box::new(move || run_import(&job_state, &settings, &properties, &file)));
+        ReadFileFuture { state: state }
+    }
+}
+
+fn run_import(state: &Mutex<State>, settings: &ImportSettings, properties: &[(String, RawProperty)],
+              file: &str) {
+    if state.lock().unwrap().cancelled {
+        return;
+    }
+
+    // Keep the worker alive if the import panics
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut importer = Importer::with_settings(settings);
+        importer.apply_raw_properties(properties);
+        importer.read_file_with_log(file).0
+    }));
+    let result = match result {
+        Ok(Ok(scene)) => Ok(OwnedScene::new(scene)),
+        Ok(Err(error)) => Err(ImportError::Failed(error)),
+        Err(_) => Err(ImportError::Failed(format!("import of {} panicked", file)))
+    };
+
+    let mut state = state.lock().unwrap();
+    if !state.cancelled {
+        state.result = Some(result);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -15,24 +15,79 @@
 
 use std::ffi::{CStr, CString};
 use std::mem;
+use std::os::raw::{c_char, c_uint};
 use std::ptr;
-use std::str;
+use std::time::Instant;
 
 use ffi::*;
 use ffi::config::*;
 
+use log::{LogMessage, LogStream, Severity, ThreadCapture};
 use math::matrix4::*;
 use scene::*;
 
+mod batch;
+mod config_key;
+mod description;
+mod error;
+#[cfg(feature = "async")]
+mod future;
+mod report;
+mod settings;
 pub mod structs;
+pub use self::batch::{import_batch, import_batch_each, import_batch_process};
+pub use self::batch::import_batch_with_progress;
+pub use self::batch::{BatchOptions, BatchProgress, BatchResult};
+pub use self::config_key::ConfigKey;
+pub use self::description::{ImporterDescription, ImporterDescriptionIter};
+pub use self::error::ImportError;
+#[cfg(feature = "async")]
+pub use self::future::ReadFileFuture;
+pub use self::report::{ImportReport, StepTiming};
+pub use self::settings::ImportSettings;
+pub(crate) use self::description::extension_of;
+use self::report::parse_profiler_log;
 use self::structs::*;
 
+// Post-processing steps unknown to assimp-sys, merged with the regular flags when importing.
+// Older versions of Assimp ignore them.
+const AIPROCESS_POPULATE_ARMATURE_DATA: c_uint = 0x4000;
+const AIPROCESS_GLOBAL_SCALE: c_uint = 0x8000000;
+const AIPROCESS_EMBED_TEXTURES: c_uint = 0x10000000;
+const AIPROCESS_FORCE_GEN_NORMALS: c_uint = 0x20000000;
+const AIPROCESS_DROP_NORMALS: c_uint = 0x40000000;
+const AIPROCESS_GEN_BOUNDING_BOXES: c_uint = 0x80000000;
+
+// The functions of assimp-sys taking post-processing steps only accept the steps known to its
+// `AiPostProcessSteps`, so they're redeclared taking the raw `c_uint` Assimp expects.
+extern "C" {
+    fn aiImportFileExWithProperties(file: *const c_char, flags: c_uint, fs: *mut AiFileIO,
+                                    props: *const AiPropertyStore) -> *const AiScene;
+    fn aiImportFileFromMemoryWithProperties(buffer: *const c_char, length: c_uint, flags: c_uint,
+                                            hint: *const c_char, props: *const AiPropertyStore)
+                                            -> *const AiScene;
+    fn aiApplyPostProcessing(scene: *const AiScene, flags: c_uint) -> *const AiScene;
+}
+
+// Value of a property set with `set_property_int` and similar methods.
+#[derive(Clone, Debug)]
+enum RawProperty {
+    Int(i32),
+    Float(f32),
+    String(String),
+    Matrix(Matrix4x4)
+}
+
 /// The `Importer` type.
 ///
 /// See [module-level documentation](index.html) for examples.
 pub struct Importer {
     property_store: *mut AiPropertyStore,
-    flags: AiPostProcessSteps
+    flags: AiPostProcessSteps,
+    extra_flags: c_uint,
+    settings: ImportSettings,
+    // Properties set with `set_property_*`, which aren't part of `settings`
+    raw_properties: Vec<(String, RawProperty)>
 }
 
 impl Importer {
@@ -40,39 +95,213 @@ impl Importer {
     pub fn new() -> Importer {
         Importer {
             property_store: unsafe { aiCreatePropertyStore() },
-            flags: AiPostProcessSteps::empty()
+            flags: AiPostProcessSteps::empty(),
+            extra_flags: 0,
+            settings: ImportSettings::default(),
+            raw_properties: Vec::new()
         }
     }
 
+    /// Create a new Importer configured from an `ImportSettings` value.
+    ///
+    /// This is equivalent to creating an `Importer` with `new` and then calling the
+    /// configuration method for each field of `settings`.
+    pub fn with_settings(settings: &ImportSettings) -> Importer {
+        let mut importer = Importer::new();
+        importer.apply_settings(settings);
+        importer
+    }
+
+    /// Returns the current configuration of the importer.
+    ///
+    /// The returned value reflects every configuration method called on the importer so far,
+    /// and can be passed to `with_settings` to create an identically configured importer.
+    pub fn settings(&self) -> &ImportSettings {
+        &self.settings
+    }
+
+    /// Apply every field of an `ImportSettings` value to the importer.
+    fn apply_settings(&mut self, settings: &ImportSettings) {
+        let s = settings;
+
+        self.measure_time(s.measure_time);
+        self.favour_speed(s.favour_speed);
+
+        self.calc_tangent_space(|x| *x = s.calc_tangent_space.clone());
+        self.join_identical_vertices(s.join_identical_vertices);
+        self.make_left_handed(s.make_left_handed);
+        self.triangulate(s.triangulate);
+        self.remove_component(|x| *x = s.remove_component.clone());
+        self.generate_normals(|x| *x = s.generate_normals.clone());
+        self.split_large_meshes(|x| *x = s.split_large_meshes.clone());
+        self.pre_transform_vertices(|x| *x = s.pre_transform_vertices.clone());
+        self.limit_bone_weights(|x| *x = s.limit_bone_weights.clone());
+        self.validate_data_structure(s.validate_data_structure);
+        self.improve_cache_locality(|x| *x = s.improve_cache_locality.clone());
+        self.remove_redudant_materials(|x| *x = s.remove_redundant_materials.clone());
+        self.fix_infacing_normals(s.fix_infacing_normals);
+        self.sort_by_primitive_type(|x| *x = s.sort_by_primitive_type.clone());
+        self.find_degenerates(|x| *x = s.find_degenerates.clone());
+        self.find_invalid_data(|x| *x = s.find_invalid_data.clone());
+        self.gen_uv_coords(s.gen_uv_coords);
+        self.transform_uv_coords(|x| *x = s.transform_uv_coords.clone());
+        self.find_instances(s.find_instances);
+        self.optimize_meshes(s.optimize_meshes);
+        self.optimize_graph(|x| *x = s.optimize_graph.clone());
+        self.flip_uvs(s.flip_uvs);
+        self.flip_winding_order(s.flip_winding_order);
+        self.split_by_bone_count(|x| *x = s.split_by_bone_count.clone());
+        self.debone(|x| *x = s.debone.clone());
+        self.global_scale(|x| *x = s.global_scale.clone());
+        self.embed_textures(s.embed_textures);
+        self.drop_normals(s.drop_normals);
+        self.gen_bounding_boxes(s.gen_bounding_boxes);
+        self.populate_armature_data(s.populate_armature_data);
+
+        self.import_no_skeleton_meshes(s.import_no_skeleton_meshes);
+        if let Some(ref path) = s.import_mdl_colormap { self.import_mdl_colormap(path); }
+        self.fbx_read_all_geometry_layers(s.fbx_read_all_geometry_layers);
+        self.fbx_read_all_materials(s.fbx_read_all_materials);
+        self.fbx_read_materials(s.fbx_read_materials);
+        self.fbx_read_textures(s.fbx_read_textures);
+        self.fbx_read_cameras(s.fbx_read_cameras);
+        self.fbx_read_lights(s.fbx_read_lights);
+        self.fbx_read_animations(s.fbx_read_animations);
+        self.fbx_strict_mode(s.fbx_strict_mode);
+        self.fbx_preserve_pivots(s.fbx_preserve_pivots);
+        self.fbx_optimize_empty_animation_curves(s.fbx_optimize_empty_animation_curves);
+        if let Some(frame) = s.global_keyframe { self.global_keyframe(frame); }
+        if let Some(frame) = s.md3_keyframe { self.md3_keyframe(frame); }
+        if let Some(frame) = s.md2_keyframe { self.md2_keyframe(frame); }
+        if let Some(frame) = s.mdl_keyframe { self.mdl_keyframe(frame); }
+        if let Some(frame) = s.mdc_keyframe { self.mdc_keyframe(frame); }
+        if let Some(frame) = s.smd_keyframe { self.smd_keyframe(frame); }
+        if let Some(frame) = s.unreal_keyframe { self.unreal_keyframe(frame); }
+        self.ac_separate_bf_cull(s.ac_separate_bf_cull);
+        self.ac_eval_subdivision(s.ac_eval_subdivision);
+        self.unreal_handle_flags(s.unreal_handle_flags);
+        self.ter_make_uvs(s.ter_make_uvs);
+        self.ase_reconstruct_normals(s.ase_reconstruct_normals);
+        self.md3_handle_multipart(s.md3_handle_multipart);
+        if let Some(ref name) = s.md3_skin_name { self.md3_skin_name(name); }
+        if let Some(ref path) = s.md3_shader_src { self.md3_shader_src(path); }
+        match s.lwo_one_layer_only {
+            Some(LwoLayer::Name(ref name)) => self.lwo_one_layer_only_str(name),
+            Some(LwoLayer::Index(index)) => self.lwo_one_layer_only_int(index),
+            None => ()
+        }
+        self.md5_no_anim_autoload(s.md5_no_anim_autoload);
+        if let Some(frame) = s.lws_anim_start { self.lws_anim_start(frame); }
+        if let Some(frame) = s.lws_anim_end { self.lws_anim_end(frame); }
+        if let Some(fps) = s.irr_anim_fps { self.irr_anim_fps(fps); }
+        if let Some(ref file) = s.ogre_material_file { self.ogre_material_file(file); }
+        self.ogre_texture_type_from_filename(s.ogre_texture_type_from_filename);
+        self.ifc_skip_space_representations(s.ifc_skip_space_representations);
+        self.ifc_skip_curve_representations(s.ifc_skip_curve_representations);
+        self.ifc_custom_triangulation(s.ifc_custom_triangulation);
+        self.collada_ignore_up_direction(s.collada_ignore_up_direction);
+    }
+
     /// Load a scene from the specified file.
     ///
     /// If the call succeeds, return value is `Ok`, containing the loaded `Scene` structure.
     /// If the call fails, return value is `Err`, containing the error string returned from
-    /// the Assimp library.
-    pub fn read_file<'a>(&self, file: &str) -> Result<Scene<'a>, &str> {
+    /// the Assimp library. The error string is shared by the whole process, so it may be the
+    /// error of an import failing at the same time on another thread.
+    pub fn read_file<'a>(&self, file: &str) -> Result<Scene<'a>, String> {
         let cstr = CString::new(file).unwrap();
         let raw_scene = unsafe {
             aiImportFileExWithProperties(
                 cstr.as_ptr(),
-                self.flags,
+                self.postprocess_flags(),
                 ptr::null_mut(),
                 self.property_store)
         };
         if !raw_scene.is_null() {
             Ok(Scene::from_raw(raw_scene))
         } else {
-            let error_str = unsafe { aiGetErrorString() };
-            if error_str.is_null() {
-                Err("Unknown error")
-            } else {
-                unsafe {
-                    let cstr = CStr::from_ptr(error_str);
-                    match str::from_utf8(cstr.to_bytes()) {
-                        Ok(s) => Err(s),
-                        Err(_) => Err("Unknown error")
-                    }
-                }
-            }
+            Err(last_error())
+        }
+    }
+
+    /// Load a scene from the specified file, and report how long each part of the import took.
+    ///
+    /// Time measurements are enabled for the duration of the call, and the timings Assimp writes
+    /// to its log are collected into the returned `ImportReport`, together with the memory
+    /// requirements of the loaded scene.
+    ///
+    /// Assimp's profiler writes its timings to the debug log, so verbose logging is enabled
+    /// for the duration of the call. Verbosity is global, so debug messages are written to all
+    /// attached streams during the call, and calling this while other threads change it with
+    /// `LogStream::set_verbose_logging` can result in missing timings.
+    ///
+    /// As with `read_file_with_log`, the error is the one logged by this import.
+    pub fn read_file_with_report<'a>(&mut self, file: &str) -> Result<(Scene<'a>, ImportReport), String> {
+        let cstr = CString::new(file).unwrap();
+
+        // Assimp's profiler writes to the debug log, which requires verbose logging
+        let verbose = LogStream::verbose_logging();
+        LogStream::set_verbose_logging(true);
+        self.set_bool_property(GLOB_MEASURE_TIME, true);
+        let capture = ThreadCapture::start();
+
+        let start = Instant::now();
+        let raw_scene = unsafe {
+            aiImportFileExWithProperties(
+                cstr.as_ptr(),
+                self.postprocess_flags(),
+                ptr::null_mut(),
+                self.property_store)
+        };
+        let total_time = start.elapsed();
+
+        let log = capture.finish();
+        let measure_time = self.settings.measure_time;
+        self.set_bool_property(GLOB_MEASURE_TIME, measure_time);
+        LogStream::set_verbose_logging(verbose);
+
+        if !raw_scene.is_null() {
+            let scene = Scene::from_raw(raw_scene);
+            let (import_time, steps) = parse_profiler_log(log.iter().map(|x| &x.text[..]));
+            let report = ImportReport {
+                total_time: total_time,
+                import_time: import_time,
+                steps: steps,
+                memory: scene.memory_requirements()
+            };
+            Ok((scene, report))
+        } else {
+            Err(logged_error(&log))
+        }
+    }
+
+    /// Load a scene from the specified file, capturing the warnings and errors logged by Assimp
+    /// while importing it.
+    ///
+    /// The messages are returned whether or not the import succeeds. If it fails, the error is
+    /// the one logged by this import, unlike with `read_file` where it may have been overwritten
+    /// by an import failing at the same time on another thread.
+    ///
+    /// Only messages logged by this import are captured, even if other imports are running
+    /// on other threads at the same time.
+    pub fn read_file_with_log<'a>(&self, file: &str) -> (Result<Scene<'a>, String>, Vec<LogMessage>) {
+        let capture = ThreadCapture::start();
+        let cstr = CString::new(file).unwrap();
+        let raw_scene = unsafe {
+            aiImportFileExWithProperties(
+                cstr.as_ptr(),
+                self.postprocess_flags(),
+                ptr::null_mut(),
+                self.property_store)
+        };
+        let messages: Vec<LogMessage> = capture.finish().into_iter()
+            .filter(|x| x.severity >= Severity::Warn)
+            .collect();
+
+        if !raw_scene.is_null() {
+            (Ok(Scene::from_raw(raw_scene)), messages)
+        } else {
+            (Err(logged_error(&messages)), messages)
         }
     }
 
@@ -81,31 +310,20 @@ impl Importer {
     /// If the call succeeds, return value is `Ok`, containing the loaded `Scene` structure.
     /// If the call fails, return value is `Err`, containing the error string returned from
     /// the Assimp library.
-    pub fn read_string<'a>(&self, data: &str) -> Result<Scene<'a>, &str> {
+    pub fn read_string<'a>(&self, data: &str) -> Result<Scene<'a>, String> {
         let cstr = CString::new(data).unwrap();
         let raw_scene = unsafe {
             aiImportFileFromMemoryWithProperties(
                 cstr.as_ptr(),
                 data.len() as u32,
-                self.flags,
+                self.postprocess_flags(),
                 ptr::null_mut(),
                 self.property_store)
         };
         if !raw_scene.is_null() {
             Ok(Scene::from_raw(raw_scene))
         } else {
-            let error_str = unsafe { aiGetErrorString() };
-            if error_str.is_null() {
-                Err("Unknown error")
-            } else {
-                unsafe {
-                    let cstr = CStr::from_ptr(error_str);
-                    match str::from_utf8(cstr.to_bytes()) {
-                        Ok(s) => Err(s),
-                        Err(_) => Err("Unknown error")
-                    }
-                }
-            }
+            Err(last_error())
         }
     }
 
@@ -122,7 +340,7 @@ impl Importer {
     /// The new scene, with new post-processing steps applied. Note that it is possible for this
     /// method to fail, in which case the return value is `Err`.
     pub fn apply_postprocessing<'a>(&'a self, scene: Scene<'a>) -> Result<Scene, &str> {
-        let raw_scene = unsafe { aiApplyPostProcessing(scene.to_raw(), self.flags) };
+        let raw_scene = unsafe { aiApplyPostProcessing(scene.to_raw(), self.postprocess_flags()) };
         if !raw_scene.is_null() {
             // Return original scene, Assimp applies post-processing in-place so returning
             // a new scene object would cause the scene to get double-dropped.
@@ -140,6 +358,7 @@ impl Importer {
     /// If enabled, measures the time needed for each part of the loading process (i.e. IO time,
     /// importing, postprocessing, ..) and dumps these timings to the output log.
     pub fn measure_time(&mut self, enable: bool) {
+        self.settings.measure_time = enable;
         self.set_bool_property(GLOB_MEASURE_TIME, enable);
     }
 
@@ -148,9 +367,64 @@ impl Importer {
     /// Enabling this option may result in faster loading, but it needn't. It represents just a hint
     /// to loaders and post-processing steps to use faster code paths, if possible.
     pub fn favour_speed(&mut self, enable: bool) {
+        self.settings.favour_speed = enable;
         self.set_bool_property(FAVOUR_SPEED, enable);
     }
 
+    /// Sets an integer import property.
+    ///
+    /// `key` is either a `ConfigKey` or the name of the property as a string, for properties
+    /// not covered by `ConfigKey`. Boolean properties are set as integers, with `0` being false.
+    ///
+    /// Properties set through this method are not reflected in `settings`.
+    pub fn set_property_int<K: AsRef<str>>(&mut self, key: K, value: i32) {
+        self.set_int_property(key.as_ref(), value);
+        self.record_raw_property(key.as_ref(), RawProperty::Int(value));
+    }
+
+    /// Sets a floating point import property.
+    ///
+    /// See `set_property_int` for details on `key`.
+    pub fn set_property_float<K: AsRef<str>>(&mut self, key: K, value: f32) {
+        self.set_float_property(key.as_ref(), value);
+        self.record_raw_property(key.as_ref(), RawProperty::Float(value));
+    }
+
+    /// Sets a string import property.
+    ///
+    /// See `set_property_int` for details on `key`.
+    pub fn set_property_string<K: AsRef<str>>(&mut self, key: K, value: &str) {
+        self.set_string_property(key.as_ref(), value);
+        self.record_raw_property(key.as_ref(), RawProperty::String(value.to_owned()));
+    }
+
+    /// Sets a 4x4 matrix import property.
+    ///
+    /// See `set_property_int` for details on `key`.
+    pub fn set_property_matrix<K: AsRef<str>>(&mut self, key: K, value: Matrix4x4) {
+        self.set_matrix_property(key.as_ref(), value);
+        self.record_raw_property(key.as_ref(), RawProperty::Matrix(value));
+    }
+
+    /// Helper method to remember a property set with `set_property_*`, replacing its previous
+    /// value, so it can be applied to another importer.
+    fn record_raw_property(&mut self, name: &str, value: RawProperty) {
+        self.raw_properties.retain(|x| x.0 != name);
+        self.raw_properties.push((name.to_owned(), value));
+    }
+
+    /// Helper method to set properties recorded by `record_raw_property` on another importer.
+    fn apply_raw_properties(&mut self, properties: &[(String, RawProperty)]) {
+        for &(ref name, ref value) in properties {
+            match *value {
+                RawProperty::Int(value) => self.set_property_int(name, value),
+                RawProperty::Float(value) => self.set_property_float(name, value),
+                RawProperty::String(ref value) => self.set_property_string(name, value),
+                RawProperty::Matrix(value) => self.set_property_matrix(name, value)
+            }
+        }
+    }
+
     /// Helper method to set or clear the appropriate import flag
     fn set_import_flag(&mut self, flag: AiPostProcessSteps, value: bool) {
         if value {
@@ -160,6 +434,20 @@ impl Importer {
         }
     }
 
+    /// Helper method to set or clear a post-processing step unknown to assimp-sys
+    fn set_extra_import_flag(&mut self, flag: c_uint, value: bool) {
+        if value {
+            self.extra_flags |= flag;
+        } else {
+            self.extra_flags &= !flag;
+        }
+    }
+
+    /// Helper method to get the full set of post-processing steps passed to Assimp.
+    fn postprocess_flags(&self) -> c_uint {
+        self.flags.bits() | self.extra_flags
+    }
+
     /// Helper method to set a boolean import property.
     fn set_bool_property(&mut self, name: &str, value: bool) {
         self.set_int_property(name, value as i32)
@@ -205,6 +493,8 @@ impl Importer {
             self.set_float_property(PP_CT_MAX_SMOOTHING_ANGLE, args.max_smoothing_angle);
             self.set_int_property(PP_CT_TEXTURE_CHANNEL_INDEX, args.texture_channel);
         }
+
+        self.settings.calc_tangent_space = args;
     }
 
     /// Identifies and joins identical vertex data sets within all imported meshes.
@@ -215,6 +505,7 @@ impl Importer {
     /// If this flag is not specified</b>, no vertices are referenced by more than one face and
     /// no index buffer is required for rendering.
     pub fn join_identical_vertices(&mut self, enable: bool) {
+        self.settings.join_identical_vertices = enable;
         self.set_import_flag(AIPROCESS_JOIN_IDENTICAL_VERTICES, enable);
     }
 
@@ -227,6 +518,7 @@ impl Importer {
     ///
     /// You'll probably want to consider this flag if you use Direct3D for rendering.
     pub fn make_left_handed(&mut self, enable: bool) {
+        self.settings.make_left_handed = enable;
         self.set_import_flag(AIPROCESS_MAKE_LEFT_HANDED, enable);
     }
 
@@ -240,6 +532,7 @@ impl Importer {
     /// * Enable both `triangulate` and `sort_by_primitive_type`
     /// * Ignore all point and line meshes when you process assimp's output
     pub fn triangulate(&mut self, enable: bool) {
+        self.settings.triangulate = enable;
         self.set_import_flag(AIPROCESS_TRIANGULATE, enable);
     }
 
@@ -286,6 +579,8 @@ impl Importer {
             );
             self.set_int_property(PP_RVC_FLAGS, flags as i32);
         }
+
+        self.settings.remove_component = args;
     }
 
     /// Generates normals for imported meshes.
@@ -301,6 +596,9 @@ impl Importer {
     /// allows you to specify an angle maximum for the normal smoothing algorithm. Normals exceeding
     /// this limit are not smoothed, resulting in a hard seam between two faces. Using a decent
     /// angle here (e.g. 80 degrees) results in very good visual appearance.
+    ///
+    /// The `force` property makes the step replace normals that are already present, instead of
+    /// ignoring meshes that have them. This requires Assimp 4.1 or later.
     pub fn generate_normals<F: Fn(&mut GenerateNormals)>(&mut self, closure: F) {
         let mut args = GenerateNormals::default();
         closure(&mut args);
@@ -315,6 +613,9 @@ impl Importer {
         } else {
             self.flags.remove(AIPROCESS_GEN_NORMALS | AIPROCESS_GEN_SMOOTH_NORMALS);
         }
+        self.set_extra_import_flag(AIPROCESS_FORCE_GEN_NORMALS, args.enable && args.force);
+
+        self.settings.generate_normals = args;
     }
 
     /// Splits large meshes into smaller sub-meshes.
@@ -338,6 +639,8 @@ impl Importer {
             self.set_int_property(PP_SLM_TRIANGLE_LIMIT, args.triangle_limit);
             self.set_int_property(PP_SLM_VERTEX_LIMIT, args.vertex_limit);
         }
+
+        self.settings.split_large_meshes = args;
     }
 
     /// Removes the node graph and pre-transforms all vertices with the local transformation
@@ -363,6 +666,8 @@ impl Importer {
             self.set_bool_property(PP_PTV_ADD_ROOT_TRANSFORMATION, args.add_root_transformation);
             self.set_matrix_property(PP_PTV_ROOT_TRANSFORMATION, args.root_transformation);
         }
+
+        self.settings.pre_transform_vertices = args;
     }
 
     /// Limits the number of bones simultaneously affecting a single vertex to a maximum value.
@@ -382,6 +687,8 @@ impl Importer {
         if args.enable {
             self.set_int_property(PP_LBW_MAX_WEIGHTS, args.max_weights);
         }
+
+        self.settings.limit_bone_weights = args;
     }
 
     /// Validates the imported scene data structure.
@@ -404,6 +711,7 @@ impl Importer {
     ///
     /// This post-processing step is not time-consuming. Its use is not compulsory, but recommended.
     pub fn validate_data_structure(&mut self, enable: bool) {
+        self.settings.validate_data_structure = enable;
         self.set_import_flag(AIPROCESS_VALIDATE_DATA_STRUCTURE, enable);
     }
 
@@ -423,6 +731,8 @@ impl Importer {
         if args.enable {
             self.set_int_property(PP_ICL_PTCACHE_SIZE, args.cache_size);
         }
+
+        self.settings.improve_cache_locality = args;
     }
 
     /// Searches for redundant/unreferenced materials and removes them.
@@ -446,6 +756,8 @@ impl Importer {
         if args.enable {
             self.set_string_property(PP_RRM_EXCLUDE_LIST, &args.exclude_list);
         }
+
+        self.settings.remove_redundant_materials = args;
     }
 
     /// This step tries to determine which meshes have normal vectors that are facing inwards and
@@ -458,6 +770,7 @@ impl Importer {
     /// The step inverts all in-facing normals. Generally it is recommended to enable this step,
     /// although the result is not always correct.
     pub fn fix_infacing_normals(&mut self, enable: bool) {
+        self.settings.fix_infacing_normals = enable;
         self.set_import_flag(AIPROCESS_FIX_INFACING_NORMALS, enable);
     }
 
@@ -501,6 +814,8 @@ impl Importer {
 
             self.set_int_property(PP_SBP_REMOVE, flags as i32);
         }
+
+        self.settings.sort_by_primitive_type = args;
     }
 
     /// This step searches all meshes for degenerate primitives and converts them to proper lines
@@ -532,6 +847,8 @@ impl Importer {
         if args.enable {
             self.set_bool_property(PP_FD_REMOVE, args.remove);
         }
+
+        self.settings.find_degenerates = args;
     }
 
     /// This step searches all meshes for invalid data, such as zeroed normal vectors or invalid UV
@@ -551,6 +868,8 @@ impl Importer {
         if args.enable {
             self.set_float_property(PP_FID_ANIM_ACCURACY, args.accuracy);
         }
+
+        self.settings.find_invalid_data = args;
     }
 
     /// This step converts non-UV mappings (such as spherical or cylindrical mapping) to proper
@@ -565,6 +884,7 @@ impl Importer {
     /// If this step is not requested, you'll need to process the `AI_MATKEY_MAPPING` material
     /// property in order to display all assets properly.
     pub fn gen_uv_coords(&mut self, enable: bool) {
+        self.settings.gen_uv_coords = enable;
         self.set_import_flag(AIPROCESS_GEN_UV_COORDS, enable);
     }
 
@@ -597,6 +917,8 @@ impl Importer {
             );
             self.set_int_property(PP_TUV_EVALUATE, flags as i32);
         }
+
+        self.settings.transform_uv_coords = args;
     }
 
     /// This step searches for duplicate meshes and replaces them with references to the first mesh.
@@ -608,6 +930,7 @@ impl Importer {
     /// identical meshes with different materials are currently *not* joined, although this is
     /// planned for future versions.
     pub fn find_instances(&mut self, enable: bool) {
+        self.settings.find_instances = enable;
         self.set_import_flag(AIPROCESS_FIND_INSTANCES, enable);
     }
 
@@ -619,6 +942,7 @@ impl Importer {
     /// `optimize_graph`, if possible. The flag is fully compatible with both `split_large_meshes`
     /// and `sort_by_primitive_type`.
     pub fn optimize_meshes(&mut self, enable: bool) {
+        self.settings.optimize_meshes = enable;
         self.set_import_flag(AIPROCESS_OPTIMIZE_MESHES, enable);
     }
 
@@ -649,6 +973,8 @@ impl Importer {
         if args.enable {
             self.set_string_property(PP_OG_EXCLUDE_LIST, &args.exclude_list);
         }
+
+        self.settings.optimize_graph = args;
     }
 
     /// This step flips all UV coordinates along the y-axis and adjusts material settings and
@@ -666,6 +992,7 @@ impl Importer {
     ///
     /// You'll probably want to consider this flag if you use Direct3D for rendering.
     pub fn flip_uvs(&mut self, enable: bool) {
+        self.settings.flip_uvs = enable;
         self.set_import_flag(AIPROCESS_FLIP_UVS, enable);
     }
 
@@ -682,9 +1009,20 @@ impl Importer {
     ///  x1
     /// ```
     pub fn flip_winding_order(&mut self, enable: bool) {
+        self.settings.flip_winding_order = enable;
         self.set_import_flag(AIPROCESS_FLIP_WINDING_ORDER, enable);
     }
 
+    /// Shortcut for `make_left_handed`, `flip_uvs` and `flip_winding_order`, equivalent to
+    /// Assimp's `aiProcess_ConvertToLeftHanded`.
+    ///
+    /// Together these steps convert the imported data to the conventions used by Direct3D.
+    pub fn convert_to_left_handed(&mut self, enable: bool) {
+        self.make_left_handed(enable);
+        self.flip_uvs(enable);
+        self.flip_winding_order(enable);
+    }
+
     /// This step splits meshes with many bones into sub-meshes so that each submesh has fewer or
     /// as many bones as a given limit.
     pub fn split_by_bone_count<F: Fn(&mut SplitByBoneCount)>(&mut self, closure: F) {
@@ -695,6 +1033,8 @@ impl Importer {
         if args.enable {
             self.set_int_property(PP_SBBC_MAX_BONES, args.max_bones);
         }
+
+        self.settings.split_by_bone_count = args;
     }
 
     /// This step removes bones losslessly or according to some threshold.
@@ -716,6 +1056,70 @@ impl Importer {
             self.set_float_property(PP_DB_THRESHOLD, args.threshold);
             self.set_bool_property(PP_DB_ALL_OR_NONE, args.all_or_none);
         }
+
+        self.settings.debone = args;
+    }
+
+    /// Applies a global scale factor to the imported scene.
+    ///
+    /// The scale is applied to the root node's transformation, so all positions, animations and
+    /// cameras are affected. Use the `scale_factor` property to control this.
+    ///
+    /// Requires Assimp 4.1 or later.
+    pub fn global_scale<F: Fn(&mut GlobalScale)>(&mut self, closure: F) {
+        let mut args = GlobalScale::default();
+        closure(&mut args);
+
+        self.set_extra_import_flag(AIPROCESS_GLOBAL_SCALE, args.enable);
+        if args.enable {
+            self.set_float_property(ConfigKey::GlobalScaleFactor.as_str(), args.scale_factor);
+        }
+
+        self.settings.global_scale = args;
+    }
+
+    /// Embeds textures referenced by the scene.
+    ///
+    /// Textures are loaded from the paths stored in the materials and embedded into the scene,
+    /// with the material texture paths replaced by references to the embedded textures
+    /// (`*0`, `*1`, ...).
+    ///
+    /// Requires Assimp 4.1 or later.
+    pub fn embed_textures(&mut self, enable: bool) {
+        self.settings.embed_textures = enable;
+        self.set_extra_import_flag(AIPROCESS_EMBED_TEXTURES, enable);
+    }
+
+    /// Drops the normals of all faces of all meshes.
+    ///
+    /// This is ignored if no normals are present. Combine this with `generate_normals` to discard
+    /// the normals stored in the file and compute new ones.
+    ///
+    /// Requires Assimp 4.1 or later.
+    pub fn drop_normals(&mut self, enable: bool) {
+        self.settings.drop_normals = enable;
+        self.set_extra_import_flag(AIPROCESS_DROP_NORMALS, enable);
+    }
+
+    /// Calculates an axis-aligned bounding box for every mesh.
+    ///
+    /// The boxes are returned by `Mesh::aabb` with the `assimp-5` feature.
+    ///
+    /// Requires Assimp 5.0 or later.
+    pub fn gen_bounding_boxes(&mut self, enable: bool) {
+        self.settings.gen_bounding_boxes = enable;
+        self.set_extra_import_flag(AIPROCESS_GEN_BOUNDING_BOXES, enable);
+    }
+
+    /// Populates the armature data of each bone.
+    ///
+    /// Fills in the `mArmature` and `mNode` members of each bone, linking it to the node of the
+    /// skeleton root and the node the bone represents.
+    ///
+    /// Requires Assimp 5.0 or later.
+    pub fn populate_armature_data(&mut self, enable: bool) {
+        self.settings.populate_armature_data = enable;
+        self.set_extra_import_flag(AIPROCESS_POPULATE_ARMATURE_DATA, enable);
     }
 
     /// Global setting to disable generation of skeleton dummy meshes
@@ -723,6 +1127,7 @@ impl Importer {
     /// Skeleton dummy meshes are generated as a visualization aid in cases which the input data
     /// contains no geometry, but only animation data.
     pub fn import_no_skeleton_meshes(&mut self, enable: bool) {
+        self.settings.import_no_skeleton_meshes = enable;
         self.set_bool_property(IMPORT_NO_SKELETON_MESHES, enable);
     }
 
@@ -734,6 +1139,7 @@ impl Importer {
     ///
     /// Default: colormap.lmp
     pub fn import_mdl_colormap(&mut self, path: &str) {
+        self.settings.import_mdl_colormap = Some(path.to_owned());
         self.set_string_property(IMPORT_MDL_COLORMAP, path);
     }
 
@@ -742,6 +1148,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_read_all_geometry_layers(&mut self, enable: bool) {
+        self.settings.fbx_read_all_geometry_layers = enable;
         self.set_bool_property(IMPORT_FBX_READ_ALL_GEOMETRY_LAYERS, enable);
     }
 
@@ -750,6 +1157,7 @@ impl Importer {
     ///
     /// Default: false.
     pub fn fbx_read_all_materials(&mut self, enable: bool) {
+        self.settings.fbx_read_all_materials = enable;
         self.set_bool_property(IMPORT_FBX_READ_ALL_MATERIALS, enable);
     }
 
@@ -757,6 +1165,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_read_materials(&mut self, enable: bool) {
+        self.settings.fbx_read_materials = enable;
         self.set_bool_property(IMPORT_FBX_READ_MATERIALS, enable);
     }
 
@@ -764,6 +1173,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_read_textures(&mut self, enable: bool) {
+        self.settings.fbx_read_textures = enable;
         self.set_bool_property(IMPORT_FBX_READ_TEXTURES, enable);
     }
 
@@ -771,6 +1181,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_read_cameras(&mut self, enable: bool) {
+        self.settings.fbx_read_cameras = enable;
         self.set_bool_property(IMPORT_FBX_READ_CAMERAS, enable);
     }
 
@@ -778,6 +1189,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_read_lights(&mut self, enable: bool) {
+        self.settings.fbx_read_lights = enable;
         self.set_bool_property(IMPORT_FBX_READ_LIGHTS, enable);
     }
 
@@ -785,6 +1197,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_read_animations(&mut self, enable: bool) {
+        self.settings.fbx_read_animations = enable;
         self.set_bool_property(IMPORT_FBX_READ_ANIMATIONS, enable);
     }
 
@@ -794,6 +1207,7 @@ impl Importer {
     ///
     /// Default: false.
     pub fn fbx_strict_mode(&mut self, enable: bool) {
+        self.settings.fbx_strict_mode = enable;
         self.set_bool_property(IMPORT_FBX_STRICT_MODE, enable);
     }
 
@@ -802,6 +1216,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_preserve_pivots(&mut self, enable: bool) {
+        self.settings.fbx_preserve_pivots = enable;
         self.set_bool_property(IMPORT_FBX_PRESERVE_PIVOTS, enable);
     }
 
@@ -810,6 +1225,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn fbx_optimize_empty_animation_curves(&mut self, enable: bool) {
+        self.settings.fbx_optimize_empty_animation_curves = enable;
         self.set_bool_property(IMPORT_FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES, enable);
     }
 
@@ -821,36 +1237,43 @@ impl Importer {
     ///
     /// Default: first frame.
     pub fn global_keyframe(&mut self, value: i32) {
+        self.settings.global_keyframe = Some(value);
         self.set_int_property(IMPORT_GLOBAL_KEYFRAME, value);
     }
 
     /// Override [`global_keyframe`](#method.global_keyframe) property for the MD3 importer.
     pub fn md3_keyframe(&mut self, value: i32) {
+        self.settings.md3_keyframe = Some(value);
         self.set_int_property(IMPORT_MD3_KEYFRAME, value);
     }
 
     /// Override [`global_keyframe`](#method.global_keyframe) property for the MD2 importer.
     pub fn md2_keyframe(&mut self, value: i32) {
+        self.settings.md2_keyframe = Some(value);
         self.set_int_property(IMPORT_MD2_KEYFRAME, value);
     }
 
     /// Override [`global_keyframe`](#method.global_keyframe) property for the MDL importer.
     pub fn mdl_keyframe(&mut self, value: i32) {
+        self.settings.mdl_keyframe = Some(value);
         self.set_int_property(IMPORT_MDL_KEYFRAME, value);
     }
 
     /// Override [`global_keyframe`](#method.global_keyframe) property for the MDC importer.
     pub fn mdc_keyframe(&mut self, value: i32) {
+        self.settings.mdc_keyframe = Some(value);
         self.set_int_property(IMPORT_MDC_KEYFRAME, value);
     }
 
     /// Override [`global_keyframe`](#method.global_keyframe) property for the SMD importer.
     pub fn smd_keyframe(&mut self, value: i32) {
+        self.settings.smd_keyframe = Some(value);
         self.set_int_property(IMPORT_SMD_KEYFRAME, value);
     }
 
     /// Override [`global_keyframe`](#method.global_keyframe) property for the Unreal importer.
     pub fn unreal_keyframe(&mut self, value: i32) {
+        self.settings.unreal_keyframe = Some(value);
         self.set_int_property(IMPORT_UNREAL_KEYFRAME, value);
     }
 
@@ -859,6 +1282,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn ac_separate_bf_cull(&mut self, enable: bool) {
+        self.settings.ac_separate_bf_cull = enable;
         self.set_bool_property(IMPORT_AC_SEPARATE_BFCULL, enable);
     }
 
@@ -868,6 +1292,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn ac_eval_subdivision(&mut self, enable: bool) {
+        self.settings.ac_eval_subdivision = enable;
         self.set_bool_property(IMPORT_AC_EVAL_SUBDIVISION, enable);
     }
 
@@ -876,6 +1301,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn unreal_handle_flags(&mut self, enable: bool) {
+        self.settings.unreal_handle_flags = enable;
         self.set_bool_property(UNREAL_HANDLE_FLAGS, enable);
     }
 
@@ -884,6 +1310,7 @@ impl Importer {
     ///
     /// Default: false.
     pub fn ter_make_uvs(&mut self, enable: bool) {
+        self.settings.ter_make_uvs = enable;
         self.set_bool_property(IMPORT_TER_MAKE_UVS, enable);
     }
 
@@ -892,6 +1319,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn ase_reconstruct_normals(&mut self, enable: bool) {
+        self.settings.ase_reconstruct_normals = enable;
         self.set_bool_property(IMPORT_ASE_RECONSTRUCT_NORMALS, enable);
     }
 
@@ -903,6 +1331,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn md3_handle_multipart(&mut self, enable: bool) {
+        self.settings.md3_handle_multipart = enable;
         self.set_bool_property(IMPORT_MD3_HANDLE_MULTIPART, enable);
     }
 
@@ -914,6 +1343,7 @@ impl Importer {
     ///
     /// Default: "default".
     pub fn md3_skin_name(&mut self, name: &str) {
+        self.settings.md3_skin_name = Some(name.to_owned());
         self.set_string_property(IMPORT_MD3_SKIN_NAME, name);
     }
 
@@ -929,6 +1359,7 @@ impl Importer {
     /// `<dir>/<model_name>.shader` first, `<dir>/<file_name>.shader` is the fallback file.
     /// Note that `<dir>` should have a terminal (back)slash.
     pub fn md3_shader_src(&mut self, path: &str) {
+        self.settings.md3_shader_src = Some(path.to_owned());
         self.set_string_property(IMPORT_MD3_SHADER_SRC, path);
     }
 
@@ -941,6 +1372,7 @@ impl Importer {
     ///
     /// Default: all layers are loaded.
     pub fn lwo_one_layer_only_str(&mut self, name: &str) {
+        self.settings.lwo_one_layer_only = Some(LwoLayer::Name(name.to_owned()));
         self.set_string_property(IMPORT_LWO_ONE_LAYER_ONLY, name);
     }
 
@@ -953,6 +1385,7 @@ impl Importer {
     ///
     /// Default: all layers are loaded.
     pub fn lwo_one_layer_only_int(&mut self, index: i32) {
+        self.settings.lwo_one_layer_only = Some(LwoLayer::Index(index));
         self.set_int_property(IMPORT_LWO_ONE_LAYER_ONLY, index);
     }
 
@@ -964,6 +1397,7 @@ impl Importer {
     ///
     /// Default: false.
     pub fn md5_no_anim_autoload(&mut self, enable: bool) {
+        self.settings.md5_no_anim_autoload = enable;
         self.set_bool_property(IMPORT_MD5_NO_ANIM_AUTOLOAD, enable);
     }
 
@@ -979,6 +1413,7 @@ impl Importer {
     ///
     /// Default: taken from file.
     pub fn lws_anim_start(&mut self, frame: i32) {
+        self.settings.lws_anim_start = Some(frame);
         self.set_int_property(IMPORT_LWS_ANIM_START, frame);
     }
 
@@ -987,6 +1422,7 @@ impl Importer {
     ///
     /// Default: taken from file.
     pub fn lws_anim_end(&mut self, frame: i32) {
+        self.settings.lws_anim_end = Some(frame);
         self.set_int_property(IMPORT_LWS_ANIM_END, frame);
     }
 
@@ -997,6 +1433,7 @@ impl Importer {
     ///
     /// Default: 100.
     pub fn irr_anim_fps(&mut self, fps: i32) {
+        self.settings.irr_anim_fps = Some(fps);
         self.set_int_property(IMPORT_IRR_ANIM_FPS, fps);
     }
 
@@ -1011,6 +1448,7 @@ impl Importer {
     ///
     /// Default value: Scene.material.
     pub fn ogre_material_file(&mut self, file: &str) {
+        self.settings.ogre_material_file = Some(file.to_owned());
         self.set_string_property(IMPORT_OGRE_MATERIAL_FILE, file);
     }
 
@@ -1032,6 +1470,7 @@ impl Importer {
     ///
     /// Default: false.
     pub fn ogre_texture_type_from_filename(&mut self, enable: bool) {
+        self.settings.ogre_texture_type_from_filename = enable;
         self.set_bool_property(IMPORT_OGRE_TEXTURETYPE_FROM_FILENAME, enable);
     }
 
@@ -1042,6 +1481,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn ifc_skip_space_representations(&mut self, enable: bool) {
+        self.settings.ifc_skip_space_representations = enable;
         self.set_bool_property(IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS, enable);
     }
 
@@ -1053,6 +1493,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn ifc_skip_curve_representations(&mut self, enable: bool) {
+        self.settings.ifc_skip_curve_representations = enable;
         self.set_bool_property(IMPORT_IFC_SKIP_CURVE_REPRESENTATIONS, enable);
     }
 
@@ -1068,6 +1509,7 @@ impl Importer {
     ///
     /// Default: true.
     pub fn ifc_custom_triangulation(&mut self, enable: bool) {
+        self.settings.ifc_custom_triangulation = enable;
         self.set_bool_property(IMPORT_IFC_CUSTOM_TRIANGULATION, enable);
     }
 
@@ -1075,6 +1517,7 @@ impl Importer {
     ///
     /// Default: false.
     pub fn collada_ignore_up_direction(&mut self, enable: bool) {
+        self.settings.collada_ignore_up_direction = enable;
         self.set_bool_property(IMPORT_COLLADA_IGNORE_UP_DIRECTION, enable);
     }
 
@@ -1093,6 +1536,54 @@ impl Importer {
         let extensions = ext_list.as_ref().split(';');
         extensions.map(|x| x.trim_left_matches("*.").to_owned()).collect()
     }
+
+    /// Returns true if Assimp has an importer for the specified file path or extension.
+    ///
+    /// Both `"models/box.obj"` and `"obj"` are accepted. Only the extension is checked, the file
+    /// doesn't need to exist.
+    pub fn can_read(path_or_ext: &str) -> bool {
+        let cstr = CString::new(format!(".{}", description::extension_of(path_or_ext))).unwrap();
+        unsafe { aiIsExtensionSupported(cstr.as_ptr()) == AI_TRUE }
+    }
+
+    /// Returns an iterator over the descriptions of all importers built into Assimp.
+    ///
+    /// Use `ImporterDescription::for_file` to find the importer handling a specific file.
+    pub fn importer_descriptions() -> ImporterDescriptionIter {
+        ImporterDescriptionIter::new()
+    }
+}
+
+/// Returns the error of a failed import from the messages it logged.
+fn logged_error(messages: &[LogMessage]) -> String {
+    // Assimp logs the reason of a failed import as its last error. The global error string
+    // isn't used as a fallback, since it may hold the error of an import on another thread.
+    messages.iter().rev()
+        .find(|x| x.severity == Severity::Error)
+        .map(|x| x.text.clone())
+        .unwrap_or_else(|| "Import failed without logging an error".to_owned())
+}
+
+/// Returns a copy of the error string of the last failed import.
+///
+/// Assimp keeps a single error string for the whole process, so it may have been overwritten
+/// by an import failing on another thread.
+fn last_error() -> String {
+    let error_str = unsafe { aiGetErrorString() };
+    if error_str.is_null() {
+        "Unknown error".to_owned()
+    } else {
+        unsafe { CStr::from_ptr(error_str).to_string_lossy().into_owned() }
+    }
+}
+
+/// Creates an importer with the same settings and properties.
+impl Clone for Importer {
+    fn clone(&self) -> Importer {
+        let mut importer = Importer::with_settings(&self.settings);
+        importer.apply_raw_properties(&self.raw_properties);
+        importer
+    }
 }
 
 impl Drop for Importer {
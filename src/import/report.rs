@@ -0,0 +1,79 @@
+//! Timing and memory reports for imports.
+
+use std::time::Duration;
+
+use scene::MemoryRequirements;
+
+/// Time spent in a single post-processing step.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepTiming {
+    /// Name of the step, as reported by Assimp (e.g. `TriangulateProcess`).
+    pub name: String,
+    /// Time spent executing the step.
+    pub time: Duration
+}
+
+/// Performance report for a single import.
+///
+/// See `Importer::read_file_with_report`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportReport {
+    /// Wall-clock time of the whole import, including all post-processing.
+    pub total_time: Duration,
+    /// Time spent loading the file, before any post-processing, as measured by Assimp.
+    pub import_time: Option<Duration>,
+    /// Time spent in each post-processing step, in the order they were executed.
+    pub steps: Vec<StepTiming>,
+    /// Memory used by the imported scene.
+    pub memory: MemoryRequirements
+}
+
+impl ImportReport {
+    /// Total time spent in post-processing steps.
+    pub fn postprocess_time(&self) -> Duration {
+        self.steps.iter().fold(Duration::new(0, 0), |total, step| total + step.time)
+    }
+}
+
+/// Parses the output of Assimp's profiler from the log.
+///
+/// With `GLOB_MEASURE_TIME` enabled Assimp logs `END   `region`, dt= <seconds> s` after each
+/// region. Post-processing steps are all logged as the `postprocess` region, so the name of each
+/// step is taken from the `<name> begin` message the step logs when it starts.
+pub fn parse_profiler_log<'a, I>(messages: I) -> (Option<Duration>, Vec<StepTiming>)
+    where I: IntoIterator<Item = &'a str>
+{
+    let mut import_time = None;
+    let mut steps = Vec::new();
+    let mut current_step: Option<&str> = None;
+
+    for msg in messages {
+        let msg = msg.trim();
+        if msg.ends_with(" begin") {
+            current_step = Some(msg.trim_end_matches(" begin"));
+        } else if msg.starts_with("END") {
+            let region = msg.split('`').nth(1);
+            let time = msg.rfind("dt=")
+                .and_then(|idx| msg[idx + 3..].trim().trim_end_matches('s').trim().parse().ok())
+                .map(duration_from_secs);
+
+            match (region, time) {
+                (Some("import"), Some(time)) => import_time = Some(time),
+                (Some("postprocess"), Some(time)) => {
+                    steps.push(StepTiming {
+                        name: current_step.take().unwrap_or("unknown").to_owned(),
+                        time: time
+                    });
+                }
+                _ => ()
+            }
+        }
+    }
+
+    (import_time, steps)
+}
+
+fn duration_from_secs(secs: f64) -> Duration {
+    let secs = secs.max(0.0);
+    Duration::new(secs.trunc() as u64, (secs.fract() * 1e9) as u32)
+}
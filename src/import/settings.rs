@@ -0,0 +1,311 @@
+//! Declarative import configuration.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::structs::*;
+
+/// A complete description of an `Importer` configuration.
+///
+/// Every post-processing step and importer property that can be configured through the methods
+/// on `Importer` has a corresponding field here. Use `Importer::with_settings` to create an
+/// importer from a settings value, and `Importer::settings` to read back the current
+/// configuration of an importer.
+///
+/// Properties stored as `Option` are left at Assimp's default when `None`.
+///
+/// # Examples
+/// ```
+/// use assimp::import::{Importer, ImportSettings};
+///
+/// let mut settings = ImportSettings::target_realtime_quality().convert_to_left_handed();
+/// settings.fbx_read_lights = false;
+///
+/// let importer = Importer::with_settings(&settings);
+/// assert_eq!(importer.settings(), &settings);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ImportSettings {
+    /// See `Importer::measure_time`. Default: false
+    pub measure_time: bool,
+    /// See `Importer::favour_speed`. Default: false
+    pub favour_speed: bool,
+
+    /// See `Importer::calc_tangent_space`.
+    pub calc_tangent_space: CalcTangentSpace,
+    /// See `Importer::join_identical_vertices`. Default: false
+    pub join_identical_vertices: bool,
+    /// See `Importer::make_left_handed`. Default: false
+    pub make_left_handed: bool,
+    /// See `Importer::triangulate`. Default: false
+    pub triangulate: bool,
+    /// See `Importer::remove_component`.
+    pub remove_component: RemoveComponent,
+    /// See `Importer::generate_normals`.
+    pub generate_normals: GenerateNormals,
+    /// See `Importer::split_large_meshes`.
+    pub split_large_meshes: SplitLargeMeshes,
+    /// See `Importer::pre_transform_vertices`.
+    pub pre_transform_vertices: PreTransformVertices,
+    /// See `Importer::limit_bone_weights`.
+    pub limit_bone_weights: LimitBoneWeights,
+    /// See `Importer::validate_data_structure`. Default: false
+    pub validate_data_structure: bool,
+    /// See `Importer::improve_cache_locality`.
+    pub improve_cache_locality: ImproveCacheLocality,
+    /// See `Importer::remove_redudant_materials`.
+    pub remove_redundant_materials: RemoveRedundantMaterials,
+    /// See `Importer::fix_infacing_normals`. Default: false
+    pub fix_infacing_normals: bool,
+    /// See `Importer::sort_by_primitive_type`.
+    pub sort_by_primitive_type: SortByPrimitiveType,
+    /// See `Importer::find_degenerates`.
+    pub find_degenerates: FindDegenerates,
+    /// See `Importer::find_invalid_data`.
+    pub find_invalid_data: FindInvalidData,
+    /// See `Importer::gen_uv_coords`. Default: false
+    pub gen_uv_coords: bool,
+    /// See `Importer::transform_uv_coords`.
+    pub transform_uv_coords: TransformUVCoords,
+    /// See `Importer::find_instances`. Default: false
+    pub find_instances: bool,
+    /// See `Importer::optimize_meshes`. Default: false
+    pub optimize_meshes: bool,
+    /// See `Importer::optimize_graph`.
+    pub optimize_graph: OptimizeGraph,
+    /// See `Importer::flip_uvs`. Default: false
+    pub flip_uvs: bool,
+    /// See `Importer::flip_winding_order`. Default: false
+    pub flip_winding_order: bool,
+    /// See `Importer::split_by_bone_count`.
+    pub split_by_bone_count: SplitByBoneCount,
+    /// See `Importer::debone`.
+    pub debone: Debone,
+    /// See `Importer::global_scale`.
+    pub global_scale: GlobalScale,
+    /// See `Importer::embed_textures`. Default: false
+    pub embed_textures: bool,
+    /// See `Importer::drop_normals`. Default: false
+    pub drop_normals: bool,
+    /// See `Importer::gen_bounding_boxes`. Default: false
+    pub gen_bounding_boxes: bool,
+    /// See `Importer::populate_armature_data`. Default: false
+    pub populate_armature_data: bool,
+
+    /// See `Importer::import_no_skeleton_meshes`. Default: false
+    pub import_no_skeleton_meshes: bool,
+    /// See `Importer::import_mdl_colormap`. Default: None
+    pub import_mdl_colormap: Option<String>,
+    /// See `Importer::fbx_read_all_geometry_layers`. Default: true
+    pub fbx_read_all_geometry_layers: bool,
+    /// See `Importer::fbx_read_all_materials`. Default: false
+    pub fbx_read_all_materials: bool,
+    /// See `Importer::fbx_read_materials`. Default: true
+    pub fbx_read_materials: bool,
+    /// See `Importer::fbx_read_textures`. Default: true
+    pub fbx_read_textures: bool,
+    /// See `Importer::fbx_read_cameras`. Default: true
+    pub fbx_read_cameras: bool,
+    /// See `Importer::fbx_read_lights`. Default: true
+    pub fbx_read_lights: bool,
+    /// See `Importer::fbx_read_animations`. Default: true
+    pub fbx_read_animations: bool,
+    /// See `Importer::fbx_strict_mode`. Default: false
+    pub fbx_strict_mode: bool,
+    /// See `Importer::fbx_preserve_pivots`. Default: true
+    pub fbx_preserve_pivots: bool,
+    /// See `Importer::fbx_optimize_empty_animation_curves`. Default: true
+    pub fbx_optimize_empty_animation_curves: bool,
+    /// See `Importer::global_keyframe`. Default: None
+    pub global_keyframe: Option<i32>,
+    /// See `Importer::md3_keyframe`. Default: None
+    pub md3_keyframe: Option<i32>,
+    /// See `Importer::md2_keyframe`. Default: None
+    pub md2_keyframe: Option<i32>,
+    /// See `Importer::mdl_keyframe`. Default: None
+    pub mdl_keyframe: Option<i32>,
+    /// See `Importer::mdc_keyframe`. Default: None
+    pub mdc_keyframe: Option<i32>,
+    /// See `Importer::smd_keyframe`. Default: None
+    pub smd_keyframe: Option<i32>,
+    /// See `Importer::unreal_keyframe`. Default: None
+    pub unreal_keyframe: Option<i32>,
+    /// See `Importer::ac_separate_bf_cull`. Default: true
+    pub ac_separate_bf_cull: bool,
+    /// See `Importer::ac_eval_subdivision`. Default: true
+    pub ac_eval_subdivision: bool,
+    /// See `Importer::unreal_handle_flags`. Default: true
+    pub unreal_handle_flags: bool,
+    /// See `Importer::ter_make_uvs`. Default: false
+    pub ter_make_uvs: bool,
+    /// See `Importer::ase_reconstruct_normals`. Default: true
+    pub ase_reconstruct_normals: bool,
+    /// See `Importer::md3_handle_multipart`. Default: true
+    pub md3_handle_multipart: bool,
+    /// See `Importer::md3_skin_name`. Default: None
+    pub md3_skin_name: Option<String>,
+    /// See `Importer::md3_shader_src`. Default: None
+    pub md3_shader_src: Option<String>,
+    /// See `Importer::lwo_one_layer_only_str` and `Importer::lwo_one_layer_only_int`.
+    /// Default: None
+    pub lwo_one_layer_only: Option<LwoLayer>,
+    /// See `Importer::md5_no_anim_autoload`. Default: false
+    pub md5_no_anim_autoload: bool,
+    /// See `Importer::lws_anim_start`. Default: None
+    pub lws_anim_start: Option<i32>,
+    /// See `Importer::lws_anim_end`. Default: None
+    pub lws_anim_end: Option<i32>,
+    /// See `Importer::irr_anim_fps`. Default: None
+    pub irr_anim_fps: Option<i32>,
+    /// See `Importer::ogre_material_file`. Default: None
+    pub ogre_material_file: Option<String>,
+    /// See `Importer::ogre_texture_type_from_filename`. Default: false
+    pub ogre_texture_type_from_filename: bool,
+    /// See `Importer::ifc_skip_space_representations`. Default: true
+    pub ifc_skip_space_representations: bool,
+    /// See `Importer::ifc_skip_curve_representations`. Default: true
+    pub ifc_skip_curve_representations: bool,
+    /// See `Importer::ifc_custom_triangulation`. Default: true
+    pub ifc_custom_triangulation: bool,
+    /// See `Importer::collada_ignore_up_direction`. Default: false
+    pub collada_ignore_up_direction: bool
+}
+
+impl Default for ImportSettings {
+    fn default() -> ImportSettings {
+        ImportSettings {
+            measure_time: false,
+            favour_speed: false,
+
+            calc_tangent_space: CalcTangentSpace::default(),
+            join_identical_vertices: false,
+            make_left_handed: false,
+            triangulate: false,
+            remove_component: RemoveComponent::default(),
+            generate_normals: GenerateNormals::default(),
+            split_large_meshes: SplitLargeMeshes::default(),
+            pre_transform_vertices: PreTransformVertices::default(),
+            limit_bone_weights: LimitBoneWeights::default(),
+            validate_data_structure: false,
+            improve_cache_locality: ImproveCacheLocality::default(),
+            remove_redundant_materials: RemoveRedundantMaterials::default(),
+            fix_infacing_normals: false,
+            sort_by_primitive_type: SortByPrimitiveType::default(),
+            find_degenerates: FindDegenerates::default(),
+            find_invalid_data: FindInvalidData::default(),
+            gen_uv_coords: false,
+            transform_uv_coords: TransformUVCoords::default(),
+            find_instances: false,
+            optimize_meshes: false,
+            optimize_graph: OptimizeGraph::default(),
+            flip_uvs: false,
+            flip_winding_order: false,
+            split_by_bone_count: SplitByBoneCount::default(),
+            debone: Debone::default(),
+            global_scale: GlobalScale::default(),
+            embed_textures: false,
+            drop_normals: false,
+            gen_bounding_boxes: false,
+            populate_armature_data: false,
+
+            import_no_skeleton_meshes: false,
+            import_mdl_colormap: None,
+            fbx_read_all_geometry_layers: true,
+            fbx_read_all_materials: false,
+            fbx_read_materials: true,
+            fbx_read_textures: true,
+            fbx_read_cameras: true,
+            fbx_read_lights: true,
+            fbx_read_animations: true,
+            fbx_strict_mode: false,
+            fbx_preserve_pivots: true,
+            fbx_optimize_empty_animation_curves: true,
+            global_keyframe: None,
+            md3_keyframe: None,
+            md2_keyframe: None,
+            mdl_keyframe: None,
+            mdc_keyframe: None,
+            smd_keyframe: None,
+            unreal_keyframe: None,
+            ac_separate_bf_cull: true,
+            ac_eval_subdivision: true,
+            unreal_handle_flags: true,
+            ter_make_uvs: false,
+            ase_reconstruct_normals: true,
+            md3_handle_multipart: true,
+            md3_skin_name: None,
+            md3_shader_src: None,
+            lwo_one_layer_only: None,
+            md5_no_anim_autoload: false,
+            lws_anim_start: None,
+            lws_anim_end: None,
+            irr_anim_fps: None,
+            ogre_material_file: None,
+            ogre_texture_type_from_filename: false,
+            ifc_skip_space_representations: true,
+            ifc_skip_curve_representations: true,
+            ifc_custom_triangulation: true,
+            collada_ignore_up_direction: false
+        }
+    }
+}
+
+impl ImportSettings {
+    /// Equivalent to `aiProcessPreset_TargetRealtime_Fast`.
+    ///
+    /// Default settings for real-time rendering applications that favour load times over
+    /// output quality. Generates flat normals and tangents, joins identical vertices,
+    /// triangulates, generates UV coordinates and sorts meshes by primitive type.
+    pub fn target_realtime_fast() -> ImportSettings {
+        let mut settings = ImportSettings::default();
+        settings.calc_tangent_space.enable = true;
+        settings.generate_normals.enable = true;
+        settings.join_identical_vertices = true;
+        settings.triangulate = true;
+        settings.gen_uv_coords = true;
+        settings.sort_by_primitive_type.enable = true;
+        settings
+    }
+
+    /// Equivalent to `aiProcessPreset_TargetRealtime_Quality`.
+    ///
+    /// Default settings for real-time rendering applications. Performs some extra optimizations
+    /// on top of `target_realtime_fast` to improve rendering speed and minimize memory usage,
+    /// and generates smooth normals.
+    pub fn target_realtime_quality() -> ImportSettings {
+        let mut settings = ImportSettings::target_realtime_fast();
+        settings.generate_normals.smooth = true;
+        settings.improve_cache_locality.enable = true;
+        settings.limit_bone_weights.enable = true;
+        settings.remove_redundant_materials.enable = true;
+        settings.split_large_meshes.enable = true;
+        settings.find_degenerates.enable = true;
+        settings.find_invalid_data.enable = true;
+        settings
+    }
+
+    /// Equivalent to `aiProcessPreset_TargetRealtime_MaxQuality`.
+    ///
+    /// Same as `target_realtime_quality`, but additionally finds instanced meshes, optimizes
+    /// meshes and validates the imported data structure.
+    pub fn target_realtime_max_quality() -> ImportSettings {
+        let mut settings = ImportSettings::target_realtime_quality();
+        settings.find_instances = true;
+        settings.validate_data_structure = true;
+        settings.optimize_meshes = true;
+        settings
+    }
+
+    /// Equivalent to adding `aiProcess_ConvertToLeftHanded` to the current settings.
+    ///
+    /// Enables `make_left_handed`, `flip_uvs` and `flip_winding_order`, which together convert
+    /// the imported data to the conventions used by Direct3D.
+    pub fn convert_to_left_handed(mut self) -> ImportSettings {
+        self.make_left_handed = true;
+        self.flip_uvs = true;
+        self.flip_winding_order = true;
+        self
+    }
+}
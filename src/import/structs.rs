@@ -1,6 +1,8 @@
 //! Argument structs for `Importer` post-processing configuration.
 
 use ffi::config::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use math::Matrix4x4;
 
@@ -9,6 +11,7 @@ use math::Matrix4x4;
 ///
 /// See `Importer::remove_component` for more details.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ComponentType {
     Normals,
     TangentsAndBitangents,
@@ -24,6 +27,7 @@ pub enum ComponentType {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UVTransformFlag {
     Scaling,
     Rotation,
@@ -32,6 +36,7 @@ pub enum UVTransformFlag {
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum PrimitiveType {
     Point,
     Line,
@@ -39,6 +44,15 @@ pub enum PrimitiveType {
     Polygon
 }
 
+/// Selects the single layer loaded by the LWO importer.
+///
+/// See `Importer::lwo_one_layer_only_str` and `Importer::lwo_one_layer_only_int`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LwoLayer {
+    Name(String),
+    Index(i32)
+}
 
 // Macro to simplify defining and structs and implementing Default trait
 // NOTE: pub keyword in field definition is to workaround rust issue #24189
@@ -47,6 +61,9 @@ macro_rules! struct_with_defaults {
         $($(#[$field_attr:meta])* pub $n:ident: $t:ty = $v:expr),*
     }) => (
         $(#[$struct_attr])*
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        #[cfg_attr(feature = "serde", serde(default))]
         pub struct $i {
             /// Whether to enable the step. Default: false
             pub enable: bool,
@@ -89,7 +106,10 @@ struct_with_defaults! {
         pub smooth: bool = false,
         /// Maximum angle between two vertex normals used for smoothing. Default: 175.0
         /// Only applies if `smooth` is `true`.
-        pub max_smoothing_angle: f32 = 175.0
+        pub max_smoothing_angle: f32 = 175.0,
+        /// Whether to generate normals even if the mesh already has them, replacing the existing
+        /// ones. Requires Assimp 4.1 or later. Default: false
+        pub force: bool = false
     }
 }
 
@@ -204,3 +224,11 @@ struct_with_defaults! {
         pub all_or_none: bool = false
     }
 }
+
+struct_with_defaults! {
+    /// Arguments for `global_scale` post-process step.
+    struct GlobalScale {
+        /// Scale factor applied to the whole scene. Default: 1.0
+        pub scale_factor: f32 = 1.0
+    }
+}
@@ -5,6 +5,19 @@
 extern crate assimp_sys as ffi;
 #[cfg(feature = "cgmath")]
 extern crate cgmath;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "log")]
+#[macro_use(log)]
+extern crate log as log_crate;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 
 pub use import::Importer;
 pub use log::LogStream;
@@ -1,12 +1,354 @@
-use std::ffi::CString;
+use std::cell::{Cell, RefCell};
+use std::ffi::{CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+#[cfg(feature = "log")]
+use log_crate;
+#[cfg(feature = "tracing")]
+use tracing;
 
 use ffi::*;
 use std::os::raw::{c_char, c_void};
 
+// Assimp doesn't provide a way to query the current verbosity, so keep track of it here.
+static VERBOSE_LOGGING: AtomicBool = AtomicBool::new(false);
+
+// Whether `forward_to_log` and `forward_to_tracing` have attached their streams.
+#[cfg(feature = "log")]
+static LOG_FORWARDED: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "tracing")]
+static TRACING_FORWARDED: AtomicBool = AtomicBool::new(false);
+
+/// Kind of a log stream attached to Assimp.
+///
+/// See `attached_streams`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamKind {
+    /// Stream writing to the file with the given name.
+    File(String),
+    StdOut,
+    StdErr,
+    Debugger,
+    /// Stream created with `LogStream::callback`.
+    Callback,
+    /// Stream created with `LogStream::from_fn`.
+    Closure,
+    /// Stream attached by `forward_to_log`.
+    Log,
+    /// Stream attached by `forward_to_tracing`.
+    Tracing
+}
+
+// All streams currently attached, in the order they were attached.
+static STREAMS: Mutex<Vec<(usize, StreamKind)>> = Mutex::new(Vec::new());
+static NEXT_STREAM_ID: AtomicUsize = AtomicUsize::new(1);
+
+fn register_stream(kind: StreamKind) -> usize {
+    let id = NEXT_STREAM_ID.fetch_add(1, Ordering::SeqCst);
+    STREAMS.lock().unwrap().push((id, kind));
+    id
+}
+
+// Returns false if the stream was already removed by `detach_all`.
+fn unregister_stream(id: usize) -> bool {
+    let mut streams = STREAMS.lock().unwrap();
+    let len = streams.len();
+    streams.retain(|&(x, _)| x != id);
+    streams.len() != len
+}
+
+fn is_stream_registered(id: usize) -> bool {
+    STREAMS.lock().unwrap().iter().any(|&(x, _)| x == id)
+}
+
+/// Returns the kinds of all streams currently attached to Assimp's log, in the order they
+/// were attached.
+///
+/// Only streams attached through this crate are listed.
+pub fn attached_streams() -> Vec<StreamKind> {
+    STREAMS.lock().unwrap().iter().map(|&(_, ref kind)| kind.clone()).collect()
+}
+
+/// Attaches a log stream, returning a guard which detaches it when dropped.
+///
+/// # Examples
+/// ```
+/// use assimp::LogStream;
+///
+/// {
+///     let _guard = assimp::log::attach(LogStream::stderr());
+///     // Messages logged here are written to stderr
+/// }
+/// // ...but not anymore once the guard is dropped
+/// ```
+pub fn attach(mut stream: LogStream) -> LogStreamGuard {
+    stream.attach();
+    LogStreamGuard(stream)
+}
+
+/// Detaches all log streams, including those attached by other parts of the program.
+///
+/// Streams created with `LogStream` are marked as detached, and the forwarding set up by
+/// `forward_to_log` or `forward_to_tracing` is removed and freed, so they can be called again.
+/// `Importer::read_file_with_log` keeps collecting messages afterwards.
+///
+/// Detaching all streams destroys Assimp's logger, so this must not be called while imports are
+/// running on other threads.
+pub fn detach_all() {
+    let _guard = DISPATCH_LOCK.lock().unwrap();
+    unsafe { aiDetachAllLogStreams(); }
+    // Messages are still collected for `read_file_with_log`, so attach the dispatch stream again
+    if DISPATCH_ATTACHED.load(Ordering::SeqCst) {
+        unsafe { aiAttachLogStream(&dispatch_stream()) }
+    }
+    SINKS.lock().unwrap().clear();
+    STREAMS.lock().unwrap().clear();
+
+    #[cfg(feature = "log")]
+    LOG_FORWARDED.store(false, Ordering::SeqCst);
+    #[cfg(feature = "tracing")]
+    TRACING_FORWARDED.store(false, Ordering::SeqCst);
+}
+
+/// Keeps a log stream attached while it's alive.
+///
+/// See `attach`.
+pub struct LogStreamGuard(LogStream);
+
+impl LogStreamGuard {
+    /// Returns the kind of the attached stream.
+    pub fn kind(&self) -> &StreamKind { &self.0.kind }
+
+    /// Detaches the stream, returning it so it can be attached again later.
+    pub fn detach(self) -> LogStream {
+        let mut stream = self.0;
+        stream.detach();
+        stream
+    }
+}
+
+/// Severity of a message written to Assimp's log.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// Only written when verbose logging is enabled.
+    Debug,
+    Info,
+    Warn,
+    Error
+}
+
+impl Severity {
+    /// Splits a message written by Assimp's logger into its severity and text.
+    ///
+    /// Assimp prefixes each message with its severity and the id of the logging thread, e.g.
+    /// `"Warn,  T0: Some warning"`. Messages without a recognised prefix are returned unchanged,
+    /// with a severity of `Info`.
+    pub fn split_message(msg: &str) -> (Severity, &str) {
+        let severity = if msg.starts_with("Debug,") {
+            Severity::Debug
+        } else if msg.starts_with("Info,") {
+            Severity::Info
+        } else if msg.starts_with("Warn,") {
+            Severity::Warn
+        } else if msg.starts_with("Error,") {
+            Severity::Error
+        } else {
+            return (Severity::Info, msg.trim_end());
+        };
+
+        let text = match msg.find(": ") {
+            Some(idx) => &msg[idx + 2..],
+            None => &msg[msg.find(',').unwrap() + 1..]
+        };
+        (severity, text.trim_end())
+    }
+}
+
+/// A message written to Assimp's log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogMessage {
+    pub severity: Severity,
+    /// Text of the message, without the prefix added by Assimp.
+    pub text: String
+}
+
+type Sink = dyn FnMut(Severity, &str) + Send;
+
+// Shared with the dispatch callback, which may still be calling the closure on another thread
+// when its stream is detached.
+type SharedSink = Arc<Mutex<Box<Sink>>>;
+
+// Closure streams attached with `LogStream::attach`.
+//
+// Assimp identifies attached streams by their callback function, so attaching several streams
+// sharing a callback doesn't work. Instead a single stream is attached to Assimp, and dispatches
+// each message to all of them.
+static SINKS: Mutex<Vec<SharedSink>> = Mutex::new(Vec::new());
+
+// Whether the dispatch stream is attached. It's attached on first use and then left attached,
+// since Assimp destroys its logger when the last stream is detached, even if another thread is
+// still logging.
+static DISPATCH_ATTACHED: AtomicBool = AtomicBool::new(false);
+
+// Serializes attaching the dispatch stream. Kept separate from SINKS so Assimp can't deadlock by
+// logging while the dispatch stream is being attached.
+static DISPATCH_LOCK: Mutex<()> = Mutex::new(());
+
+fn dispatch_stream() -> AiLogStream {
+    AiLogStream {
+        callback: Some(dispatch_callback),
+        user: ptr::null::<c_void>() as *mut c_void
+    }
+}
+
+thread_local! {
+    // Messages collected by the active `ThreadCapture` on this thread.
+    static CAPTURE: RefCell<Option<Vec<LogMessage>>> = RefCell::new(None);
+    // Whether the closures are being called on this thread, to skip messages they log themselves.
+    static DISPATCHING: Cell<bool> = Cell::new(false);
+}
+
+unsafe extern "system" fn dispatch_callback(msg: *const c_char, _user: *mut c_char) {
+    let msg = CStr::from_ptr(msg).to_string_lossy();
+    let (severity, text) = Severity::split_message(&msg);
+    let _ = CAPTURE.try_with(|capture| {
+        if let Some(ref mut messages) = *capture.borrow_mut() {
+            messages.push(LogMessage { severity: severity, text: text.to_owned() });
+        }
+    });
+    if DISPATCHING.try_with(|x| x.replace(true)).unwrap_or(true) {
+        return;
+    }
+    // Call the closures without holding the SINKS lock, so they can attach and detach streams
+    let sinks = SINKS.lock().map(|x| x.clone()).unwrap_or_default();
+    for sink in &sinks {
+        if let Ok(mut sink) = sink.lock() {
+            // Unwinding into Assimp isn't allowed, so ignore panics in the closures
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| (**sink)(severity, text)));
+        }
+    }
+    DISPATCHING.with(|x| x.set(false));
+}
+
+fn attach_dispatch_stream() {
+    let _guard = DISPATCH_LOCK.lock().unwrap();
+    if !DISPATCH_ATTACHED.swap(true, Ordering::SeqCst) {
+        unsafe { aiAttachLogStream(&dispatch_stream()) }
+    }
+}
+
+fn register_sink(sink: SharedSink) {
+    attach_dispatch_stream();
+    SINKS.lock().unwrap().push(sink);
+}
+
+fn unregister_sink(sink: &SharedSink) {
+    SINKS.lock().unwrap().retain(|x| !Arc::ptr_eq(x, sink));
+}
+
+/// Collects the messages logged on the current thread while it's alive.
+///
+/// Assimp logs synchronously on the thread calling into it, so this captures exactly the
+/// messages of the imports run by this thread, even if other threads are importing at the same
+/// time.
+pub(crate) struct ThreadCapture {
+    previous: Option<Vec<LogMessage>>
+}
+
+impl ThreadCapture {
+    pub fn start() -> ThreadCapture {
+        let previous = CAPTURE.with(|capture| capture.borrow_mut().replace(Vec::new()));
+        attach_dispatch_stream();
+        ThreadCapture { previous: previous }
+    }
+
+    /// Stops capturing and returns the collected messages.
+    pub fn finish(self) -> Vec<LogMessage> {
+        CAPTURE.with(|capture| capture.borrow_mut().take()).unwrap_or_default()
+    }
+}
+
+impl Drop for ThreadCapture {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        let _ = CAPTURE.try_with(|capture| *capture.borrow_mut() = previous);
+    }
+}
+
+/// Forwards all messages written to Assimp's log to the `log` crate.
+///
+/// Messages are logged with the `assimp` target, at the level matching their severity, and are
+/// filtered by the logger as usual. Verbose logging is enabled if debug messages are enabled by
+/// `log::max_level()` at the time of the call, so this should be called after the logger has
+/// been initialised. Later changes of the maximum level don't change Assimp's verbosity, use
+/// `LogStream::set_verbose_logging` to get or stop debug messages afterwards. Calling it while
+/// `Importer::read_file_with_report` is running on another thread may leave verbose logging
+/// in the wrong state, see `LogStream::set_verbose_logging`.
+///
+/// Calling this more than once has no effect, unless `detach_all` was called in between.
+#[cfg(feature = "log")]
+pub fn forward_to_log() {
+    if LOG_FORWARDED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    LogStream::set_verbose_logging(log_crate::max_level() >= log_crate::LevelFilter::Debug);
+    install_sink(StreamKind::Log, Box::new(|severity, msg| {
+        let level = match severity {
+            Severity::Debug => log_crate::Level::Debug,
+            Severity::Info => log_crate::Level::Info,
+            Severity::Warn => log_crate::Level::Warn,
+            Severity::Error => log_crate::Level::Error
+        };
+        log!(target: "assimp", level, "{}", msg);
+    }));
+}
+
+/// Forwards all messages written to Assimp's log to the `tracing` crate.
+///
+/// Messages are recorded as events with the `assimp` target, at the level matching their
+/// severity. Verbose logging is enabled if debug events are enabled by the current maximum
+/// level at the time of the call, so this should be called after the subscriber has been
+/// installed. Later changes of the maximum level don't change Assimp's verbosity, use
+/// `LogStream::set_verbose_logging` to get or stop debug events afterwards. Calling it while `Importer::read_file_with_report` is running on another thread
+/// may leave verbose logging in the wrong state, see `LogStream::set_verbose_logging`.
+///
+/// Calling this more than once has no effect, unless `detach_all` was called in between.
+#[cfg(feature = "tracing")]
+pub fn forward_to_tracing() {
+    use tracing::level_filters::LevelFilter;
+
+    if TRACING_FORWARDED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    LogStream::set_verbose_logging(LevelFilter::current() >= LevelFilter::DEBUG);
+    install_sink(StreamKind::Tracing, Box::new(|severity, msg| {
+        match severity {
+            Severity::Debug => tracing::debug!(target: "assimp", "{}", msg),
+            Severity::Info => tracing::info!(target: "assimp", "{}", msg),
+            Severity::Warn => tracing::warn!(target: "assimp", "{}", msg),
+            Severity::Error => tracing::error!(target: "assimp", "{}", msg)
+        }
+    }));
+}
+
+/// Attaches a closure to the log until `detach_all` is called.
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn install_sink(kind: StreamKind, sink: Box<Sink>) {
+    register_sink(Arc::new(Mutex::new(sink)));
+    register_stream(kind);
+}
+
 pub struct LogStream {
     raw: AiLogStream,
-    attached: bool
+    kind: StreamKind,
+    // Id of the stream in STREAMS while attached
+    id: Option<usize>,
+    sink: Option<SharedSink>
 }
 
 impl LogStream {
@@ -14,7 +356,7 @@ impl LogStream {
         let cstr = CString::new(filename).unwrap();
         let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::File, cstr.as_ptr()) };
         if stream.callback.is_some() {
-            Some(LogStream { raw: stream, attached: false })
+            Some(LogStream { raw: stream, kind: StreamKind::File(filename.to_owned()), id: None, sink: None })
         } else {
             None
         }
@@ -22,18 +364,18 @@ impl LogStream {
 
     pub fn stdout() -> LogStream {
         let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::StdOut, ptr::null()) };
-        LogStream { raw: stream, attached: false }
+        LogStream { raw: stream, kind: StreamKind::StdOut, id: None, sink: None }
     }
 
     pub fn stderr() -> LogStream {
         let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::StdErr, ptr::null()) };
-        LogStream { raw: stream, attached: false }
+        LogStream { raw: stream, kind: StreamKind::StdErr, id: None, sink: None }
     }
 
     #[cfg(windows)]
     pub fn debug() -> LogStream {
         let stream = unsafe { aiGetPredefinedLogStream(AiDefaultLogStream::Debugger, ptr::null()) };
-        LogStream { raw: stream, attached: false }
+        LogStream { raw: stream, kind: StreamKind::Debugger, id: None, sink: None }
     }
 
     pub fn callback(cb: unsafe extern "system" fn(*const c_char, *mut c_char)) -> LogStream {
@@ -42,23 +384,87 @@ impl LogStream {
                 callback: Some(cb),
                 user: ptr::null::<c_void>() as *mut c_void
             },
-            attached: false
+            kind: StreamKind::Callback,
+            id: None,
+            sink: None
+        }
+    }
+
+    /// Creates a log stream that passes each message to a closure.
+    ///
+    /// The closure receives the severity of each message and its text, with the prefix added by
+    /// Assimp removed. It's called from whichever thread is logging, one message at a time, and
+    /// is freed when the stream is dropped. Messages logged by Assimp from within the closure are
+    /// not passed to any closure.
+    ///
+    /// # Examples
+    /// ```
+    /// use assimp::LogStream;
+    /// use assimp::log::Severity;
+    ///
+    /// let mut stream = LogStream::from_fn(|severity, msg| {
+    ///     if severity >= Severity::Warn {
+    ///         println!("assimp: {}", msg);
+    ///     }
+    /// });
+    /// stream.attach();
+    /// ```
+    pub fn from_fn<F>(f: F) -> LogStream
+        where F: FnMut(Severity, &str) + Send + 'static
+    {
+        LogStream {
+            raw: dispatch_stream(),
+            kind: StreamKind::Closure,
+            id: None,
+            sink: Some(Arc::new(Mutex::new(Box::new(f))))
         }
     }
 
-    pub fn attached(&self) -> bool { self.attached }
+    /// Returns the kind of the stream.
+    pub fn kind(&self) -> &StreamKind { &self.kind }
+
+    /// Returns whether the stream is attached, i.e. `attach` was called and neither `detach`
+    /// nor `detach_all` was called since.
+    pub fn attached(&self) -> bool {
+        self.id.map_or(false, is_stream_registered)
+    }
 
     pub fn attach(&mut self) {
-        if !self.attached { unsafe { aiAttachLogStream(&self.raw) } }
+        if !self.attached() {
+            match self.sink {
+                Some(ref sink) => register_sink(sink.clone()),
+                None => unsafe { aiAttachLogStream(&self.raw) }
+            }
+            self.id = Some(register_stream(self.kind.clone()));
+        }
     }
 
     pub fn detach(&mut self) {
-        if self.attached { unsafe { aiDetachLogStream(&self.raw); } }
+        // Nothing to do if the stream was already detached by `detach_all`
+        if self.id.take().map_or(false, unregister_stream) {
+            match self.sink {
+                Some(ref sink) => unregister_sink(sink),
+                None => unsafe { aiDetachLogStream(&self.raw); }
+            }
+        }
     }
 
+    /// Enables or disables debug messages.
+    ///
+    /// This takes effect immediately for all attached streams. Verbosity is global, and
+    /// `Importer::read_file_with_report` enables it for the duration of its call, so a change
+    /// made while it's running on another thread is overwritten when it returns.
     pub fn set_verbose_logging(state: bool) {
+        VERBOSE_LOGGING.store(state, Ordering::SeqCst);
         unsafe { aiEnableVerboseLogging(if state { AI_TRUE } else { AI_FALSE }) }
     }
+
+    /// Returns whether verbose logging was enabled with `set_verbose_logging`.
+    ///
+    /// While `Importer::read_file_with_report` is running on any thread this returns true.
+    pub fn verbose_logging() -> bool {
+        VERBOSE_LOGGING.load(Ordering::SeqCst)
+    }
 }
 
 impl Drop for LogStream {
@@ -0,0 +1,105 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{Matrix4x4, Vector3D};
+
+/// Axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Aabb {
+    pub min: Vector3D,
+    pub max: Vector3D
+}
+
+impl Aabb {
+    pub fn new(min: Vector3D, max: Vector3D) -> Aabb {
+        Aabb { min: min, max: max }
+    }
+
+    /// Returns the smallest box containing all the points, or `None` if there are none.
+    pub fn from_points<I>(points: I) -> Option<Aabb>
+        where I: IntoIterator<Item = Vector3D>
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb::new(first, first), |aabb, p| aabb.extend(p)))
+    }
+
+    pub fn center(&self) -> Vector3D {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Returns the size of the box along each axis.
+    pub fn size(&self) -> Vector3D {
+        self.max - self.min
+    }
+
+    pub fn contains(&self, p: Vector3D) -> bool {
+        p.x >= self.min.x && p.y >= self.min.y && p.z >= self.min.z &&
+        p.x <= self.max.x && p.y <= self.max.y && p.z <= self.max.z
+    }
+
+    /// Returns the smallest box containing both this box and the point.
+    pub fn extend(&self, p: Vector3D) -> Aabb {
+        Aabb::new(Vector3D::new(self.min.x.min(p.x), self.min.y.min(p.y), self.min.z.min(p.z)),
+                  Vector3D::new(self.max.x.max(p.x), self.max.y.max(p.y), self.max.z.max(p.z)))
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        self.extend(other.min).extend(other.max)
+    }
+
+    /// Returns the corners of the box.
+    pub fn corners(&self) -> [Vector3D; 8] {
+        let (a, b) = (self.min, self.max);
+        [Vector3D::new(a.x, a.y, a.z), Vector3D::new(b.x, a.y, a.z),
+         Vector3D::new(a.x, b.y, a.z), Vector3D::new(b.x, b.y, a.z),
+         Vector3D::new(a.x, a.y, b.z), Vector3D::new(b.x, a.y, b.z),
+         Vector3D::new(a.x, b.y, b.z), Vector3D::new(b.x, b.y, b.z)]
+    }
+
+    /// Returns the axis-aligned box containing this box once transformed.
+    ///
+    /// The result is larger than the transformed contents if the transformation has a rotation.
+    pub fn transform(&self, m: &Matrix4x4) -> Aabb {
+        let corners = self.corners();
+        Aabb::from_points(corners.iter().map(|&p| *m * p)).unwrap()
+    }
+
+    /// Returns the smallest sphere containing the box.
+    pub fn bounding_sphere(&self) -> BoundingSphere {
+        BoundingSphere::new(self.center(), self.size().length() * 0.5)
+    }
+}
+
+/// Bounding sphere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoundingSphere {
+    pub center: Vector3D,
+    pub radius: f32
+}
+
+impl BoundingSphere {
+    pub fn new(center: Vector3D, radius: f32) -> BoundingSphere {
+        BoundingSphere { center: center, radius: radius }
+    }
+
+    /// Returns a sphere containing all the points, or `None` if there are none.
+    ///
+    /// The sphere is centered on the bounding box of the points, which is quick to compute
+    /// but not always the smallest sphere.
+    pub fn from_points<I>(points: I) -> Option<BoundingSphere>
+        where I: IntoIterator<Item = Vector3D>, I::IntoIter: Clone
+    {
+        let points = points.into_iter();
+        let center = Aabb::from_points(points.clone())?.center();
+        let radius = points.fold(0.0f32, |radius, p| radius.max((p - center).square_length()));
+        Some(BoundingSphere::new(center, radius.sqrt()))
+    }
+
+    pub fn contains(&self, p: Vector3D) -> bool {
+        (p - self.center).square_length() <= self.radius * self.radius
+    }
+}
@@ -1,7 +1,17 @@
 #[cfg(feature = "cgmath")]
 use cgmath::Vector3;
+#[cfg(feature = "glam")]
+use glam::Vec3;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiColor3D;
 
+use std::ops::{Add, Mul, Sub};
+
 define_type! {
     /// Color3D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -12,6 +22,40 @@ impl Color3D {
     pub fn new(r: f32, g: f32, b: f32) -> Color3D {
         Color3D(AiColor3D { r: r, g: g, b: b })
     }
+
+    /// Returns true if all components are zero.
+    pub fn is_black(&self) -> bool {
+        self.r == 0.0 && self.g == 0.0 && self.b == 0.0
+    }
+}
+
+impl Add for Color3D {
+    type Output = Color3D;
+    fn add(self, rhs: Color3D) -> Color3D {
+        Color3D::new(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b)
+    }
+}
+
+impl Sub for Color3D {
+    type Output = Color3D;
+    fn sub(self, rhs: Color3D) -> Color3D {
+        Color3D::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b)
+    }
+}
+
+/// Component-wise multiplication.
+impl Mul for Color3D {
+    type Output = Color3D;
+    fn mul(self, rhs: Color3D) -> Color3D {
+        Color3D::new(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b)
+    }
+}
+
+impl Mul<f32> for Color3D {
+    type Output = Color3D;
+    fn mul(self, rhs: f32) -> Color3D {
+        Color3D::new(self.r * rhs, self.g * rhs, self.b * rhs)
+    }
 }
 
 impl From<[f32; 3]> for Color3D {
@@ -39,3 +83,60 @@ impl From<Color3D> for Vector3<f32> {
         Vector3::new(c.r, c.g, c.b)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for Color3D {
+    fn from(v: Vec3) -> Color3D {
+        Color3D::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Color3D> for Vec3 {
+    fn from(c: Color3D) -> Vec3 {
+        Vec3::new(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Vector3<f32>> for Color3D {
+    fn from(v: na::Vector3<f32>) -> Color3D {
+        Color3D::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Color3D> for na::Vector3<f32> {
+    fn from(c: Color3D) -> na::Vector3<f32> {
+        na::Vector3::new(c.r, c.g, c.b)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f32>> for Color3D {
+    fn from(v: mint::Vector3<f32>) -> Color3D {
+        Color3D::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Color3D> for mint::Vector3<f32> {
+    fn from(c: Color3D) -> mint::Vector3<f32> {
+        mint::Vector3 { x: c.r, y: c.g, z: c.b }
+    }
+}
+
+/// Serialized as an `[r, g, b]` array.
+#[cfg(feature = "serde")]
+impl Serialize for Color3D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        <[f32; 3]>::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Color3D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color3D, D::Error> {
+        <[f32; 3]>::deserialize(deserializer).map(Color3D::from)
+    }
+}
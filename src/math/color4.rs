@@ -1,7 +1,17 @@
 #[cfg(feature = "cgmath")]
 use cgmath::Vector4;
+#[cfg(feature = "glam")]
+use glam::Vec4;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiColor4D;
 
+use std::ops::{Add, Mul, Sub};
+
 define_type_and_iterator! {
     /// Color4D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -14,6 +24,40 @@ impl Color4D {
     pub fn new(r: f32, g: f32, b: f32, a: f32) -> Color4D {
         Color4D(AiColor4D { r: r, g: g, b: b, a: a })
     }
+
+    /// Returns true if all color components are zero. Alpha is ignored.
+    pub fn is_black(&self) -> bool {
+        self.r == 0.0 && self.g == 0.0 && self.b == 0.0
+    }
+}
+
+impl Add for Color4D {
+    type Output = Color4D;
+    fn add(self, rhs: Color4D) -> Color4D {
+        Color4D::new(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b, self.a + rhs.a)
+    }
+}
+
+impl Sub for Color4D {
+    type Output = Color4D;
+    fn sub(self, rhs: Color4D) -> Color4D {
+        Color4D::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b, self.a - rhs.a)
+    }
+}
+
+/// Component-wise multiplication.
+impl Mul for Color4D {
+    type Output = Color4D;
+    fn mul(self, rhs: Color4D) -> Color4D {
+        Color4D::new(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b, self.a * rhs.a)
+    }
+}
+
+impl Mul<f32> for Color4D {
+    type Output = Color4D;
+    fn mul(self, rhs: f32) -> Color4D {
+        Color4D::new(self.r * rhs, self.g * rhs, self.b * rhs, self.a * rhs)
+    }
 }
 
 impl From<[f32; 4]> for Color4D {
@@ -41,3 +85,60 @@ impl From<Color4D> for Vector4<f32> {
         Vector4::new(c.r, c.g, c.b, c.a)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Vec4> for Color4D {
+    fn from(v: Vec4) -> Color4D {
+        Color4D::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Color4D> for Vec4 {
+    fn from(c: Color4D) -> Vec4 {
+        Vec4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Vector4<f32>> for Color4D {
+    fn from(v: na::Vector4<f32>) -> Color4D {
+        Color4D::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Color4D> for na::Vector4<f32> {
+    fn from(c: Color4D) -> na::Vector4<f32> {
+        na::Vector4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector4<f32>> for Color4D {
+    fn from(v: mint::Vector4<f32>) -> Color4D {
+        Color4D::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Color4D> for mint::Vector4<f32> {
+    fn from(c: Color4D) -> mint::Vector4<f32> {
+        mint::Vector4 { x: c.r, y: c.g, z: c.b, w: c.a }
+    }
+}
+
+/// Serialized as an `[r, g, b, a]` array.
+#[cfg(feature = "serde")]
+impl Serialize for Color4D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        <[f32; 4]>::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Color4D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Color4D, D::Error> {
+        <[f32; 4]>::deserialize(deserializer).map(Color4D::from)
+    }
+}
@@ -1,7 +1,19 @@
 #[cfg(feature = "cgmath")]
 use cgmath::Matrix3;
+#[cfg(feature = "glam")]
+use glam::Mat3;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiMatrix3x3;
 
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Matrix4x4, Quaternion, Vector3D};
+
 define_type! {
     /// Matrix3x3 docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -18,6 +30,114 @@ impl Matrix3x3 {
             c1: c2r0, c2: c2r1, c3: c2r2,
         })
     }
+
+    /// Returns the identity matrix.
+    pub fn identity() -> Matrix3x3 {
+        Matrix3x3::new(1.0, 0.0, 0.0,
+                       0.0, 1.0, 0.0,
+                       0.0, 0.0, 1.0)
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Matrix3x3 {
+        Matrix3x3::new(self.a1, self.b1, self.c1,
+                       self.a2, self.b2, self.c2,
+                       self.a3, self.b3, self.c3)
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> f32 {
+        self.a1 * self.b2 * self.c3 - self.a1 * self.b3 * self.c2
+            + self.a2 * self.b3 * self.c1 - self.a2 * self.b1 * self.c3
+            + self.a3 * self.b1 * self.c2 - self.a3 * self.b2 * self.c1
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix3x3> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv = 1.0 / det;
+        Some(Matrix3x3::new(
+            (self.b2 * self.c3 - self.b3 * self.c2) * inv,
+            -(self.a2 * self.c3 - self.a3 * self.c2) * inv,
+            (self.a2 * self.b3 - self.a3 * self.b2) * inv,
+            -(self.b1 * self.c3 - self.b3 * self.c1) * inv,
+            (self.a1 * self.c3 - self.a3 * self.c1) * inv,
+            -(self.a1 * self.b3 - self.a3 * self.b1) * inv,
+            (self.b1 * self.c2 - self.b2 * self.c1) * inv,
+            -(self.a1 * self.c2 - self.a2 * self.c1) * inv,
+            (self.a1 * self.b2 - self.a2 * self.b1) * inv))
+    }
+}
+
+impl Add for Matrix3x3 {
+    type Output = Matrix3x3;
+    fn add(self, m: Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::new(self.a1 + m.a1, self.a2 + m.a2, self.a3 + m.a3,
+                       self.b1 + m.b1, self.b2 + m.b2, self.b3 + m.b3,
+                       self.c1 + m.c1, self.c2 + m.c2, self.c3 + m.c3)
+    }
+}
+
+impl Sub for Matrix3x3 {
+    type Output = Matrix3x3;
+    fn sub(self, m: Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::new(self.a1 - m.a1, self.a2 - m.a2, self.a3 - m.a3,
+                       self.b1 - m.b1, self.b2 - m.b2, self.b3 - m.b3,
+                       self.c1 - m.c1, self.c2 - m.c2, self.c3 - m.c3)
+    }
+}
+
+impl Neg for Matrix3x3 {
+    type Output = Matrix3x3;
+    fn neg(self) -> Matrix3x3 {
+        Matrix3x3::new(-self.a1, -self.a2, -self.a3,
+                       -self.b1, -self.b2, -self.b3,
+                       -self.c1, -self.c2, -self.c3)
+    }
+}
+
+impl Mul for Matrix3x3 {
+    type Output = Matrix3x3;
+    fn mul(self, m: Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::new(
+            m.a1 * self.a1 + m.b1 * self.a2 + m.c1 * self.a3,
+            m.a2 * self.a1 + m.b2 * self.a2 + m.c2 * self.a3,
+            m.a3 * self.a1 + m.b3 * self.a2 + m.c3 * self.a3,
+            m.a1 * self.b1 + m.b1 * self.b2 + m.c1 * self.b3,
+            m.a2 * self.b1 + m.b2 * self.b2 + m.c2 * self.b3,
+            m.a3 * self.b1 + m.b3 * self.b2 + m.c3 * self.b3,
+            m.a1 * self.c1 + m.b1 * self.c2 + m.c1 * self.c3,
+            m.a2 * self.c1 + m.b2 * self.c2 + m.c2 * self.c3,
+            m.a3 * self.c1 + m.b3 * self.c2 + m.c3 * self.c3)
+    }
+}
+
+impl Mul<Vector3D> for Matrix3x3 {
+    type Output = Vector3D;
+    fn mul(self, v: Vector3D) -> Vector3D {
+        Vector3D::new(self.a1 * v.x + self.a2 * v.y + self.a3 * v.z,
+                       self.b1 * v.x + self.b2 * v.y + self.b3 * v.z,
+                       self.c1 * v.x + self.c2 * v.y + self.c3 * v.z)
+    }
+}
+
+/// Extracts the upper-left 3x3 part of the matrix.
+impl From<Matrix4x4> for Matrix3x3 {
+    fn from(m: Matrix4x4) -> Matrix3x3 {
+        Matrix3x3::new(m.a1, m.a2, m.a3,
+                       m.b1, m.b2, m.b3,
+                       m.c1, m.c2, m.c3)
+    }
+}
+
+impl From<Quaternion> for Matrix3x3 {
+    fn from(q: Quaternion) -> Matrix3x3 {
+        q.to_matrix()
+    }
 }
 
 #[cfg(feature = "cgmath")]
@@ -37,3 +157,101 @@ impl From<Matrix3x3> for Matrix3<f32> {
                      mat.a3, mat.b3, mat.c3)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Mat3> for Matrix3x3 {
+    fn from(mat: Mat3) -> Matrix3x3 {
+        let c = mat.to_cols_array_2d();
+        Matrix3x3::new(c[0][0], c[1][0], c[2][0],
+                       c[0][1], c[1][1], c[2][1],
+                       c[0][2], c[1][2], c[2][2])
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Matrix3x3> for Mat3 {
+    fn from(mat: Matrix3x3) -> Mat3 {
+        Mat3::from_cols_array_2d(&[[mat.a1, mat.b1, mat.c1],
+                                   [mat.a2, mat.b2, mat.c2],
+                                   [mat.a3, mat.b3, mat.c3]])
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Matrix3<f32>> for Matrix3x3 {
+    fn from(mat: na::Matrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(mat[(0, 0)], mat[(0, 1)], mat[(0, 2)],
+                       mat[(1, 0)], mat[(1, 1)], mat[(1, 2)],
+                       mat[(2, 0)], mat[(2, 1)], mat[(2, 2)])
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Matrix3x3> for na::Matrix3<f32> {
+    fn from(mat: Matrix3x3) -> na::Matrix3<f32> {
+        na::Matrix3::new(mat.a1, mat.a2, mat.a3,
+                         mat.b1, mat.b2, mat.b3,
+                         mat.c1, mat.c2, mat.c3)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::RowMatrix3<f32>> for Matrix3x3 {
+    fn from(mat: mint::RowMatrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(mat.x.x, mat.x.y, mat.x.z,
+                       mat.y.x, mat.y.y, mat.y.z,
+                       mat.z.x, mat.z.y, mat.z.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix3x3> for mint::RowMatrix3<f32> {
+    fn from(mat: Matrix3x3) -> mint::RowMatrix3<f32> {
+        mint::RowMatrix3 {
+            x: mint::Vector3 { x: mat.a1, y: mat.a2, z: mat.a3 },
+            y: mint::Vector3 { x: mat.b1, y: mat.b2, z: mat.b3 },
+            z: mint::Vector3 { x: mat.c1, y: mat.c2, z: mat.c3 },
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix3<f32>> for Matrix3x3 {
+    fn from(mat: mint::ColumnMatrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(mat.x.x, mat.y.x, mat.z.x,
+                       mat.x.y, mat.y.y, mat.z.y,
+                       mat.x.z, mat.y.z, mat.z.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix3x3> for mint::ColumnMatrix3<f32> {
+    fn from(mat: Matrix3x3) -> mint::ColumnMatrix3<f32> {
+        mint::ColumnMatrix3 {
+            x: mint::Vector3 { x: mat.a1, y: mat.b1, z: mat.c1 },
+            y: mint::Vector3 { x: mat.a2, y: mat.b2, z: mat.c2 },
+            z: mint::Vector3 { x: mat.a3, y: mat.b3, z: mat.c3 },
+        }
+    }
+}
+
+/// Serialized as an array of rows, matching Assimp's row-major layout.
+#[cfg(feature = "serde")]
+impl Serialize for Matrix3x3 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [[self.a1, self.a2, self.a3],
+         [self.b1, self.b2, self.b3],
+         [self.c1, self.c2, self.c3]].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Matrix3x3 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Matrix3x3, D::Error> {
+        <[[f32; 3]; 3]>::deserialize(deserializer).map(|r| {
+            Matrix3x3::new(r[0][0], r[0][1], r[0][2],
+                           r[1][0], r[1][1], r[1][2],
+                           r[2][0], r[2][1], r[2][2])
+        })
+    }
+}
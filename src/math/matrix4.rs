@@ -1,7 +1,19 @@
 #[cfg(feature = "cgmath")]
 use cgmath::Matrix4;
+#[cfg(feature = "glam")]
+use glam::Mat4;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiMatrix4x4;
 
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Matrix3x3, Quaternion, Vector3D};
+
 define_type! {
     /// Matrix4x4 docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -20,6 +32,205 @@ impl Matrix4x4 {
             d1: c3r0, d2: c3r1, d3: c3r2, d4: c3r3,
         })
     }
+
+    /// Returns the identity matrix.
+    pub fn identity() -> Matrix4x4 {
+        Matrix4x4::new(1.0, 0.0, 0.0, 0.0,
+                       0.0, 1.0, 0.0, 0.0,
+                       0.0, 0.0, 1.0, 0.0,
+                       0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Returns a translation matrix.
+    pub fn from_translation(v: Vector3D) -> Matrix4x4 {
+        Matrix4x4::new(1.0, 0.0, 0.0, v.x,
+                       0.0, 1.0, 0.0, v.y,
+                       0.0, 0.0, 1.0, v.z,
+                       0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Returns a scaling matrix.
+    pub fn from_scaling(v: Vector3D) -> Matrix4x4 {
+        Matrix4x4::new(v.x, 0.0, 0.0, 0.0,
+                       0.0, v.y, 0.0, 0.0,
+                       0.0, 0.0, v.z, 0.0,
+                       0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Builds a transformation matrix from scaling, rotation and translation components.
+    ///
+    /// This is the inverse of [`decompose`](#method.decompose).
+    pub fn compose(scaling: Vector3D, rotation: Quaternion, translation: Vector3D) -> Matrix4x4 {
+        let m = rotation.to_matrix();
+        Matrix4x4::new(m.a1 * scaling.x, m.a2 * scaling.y, m.a3 * scaling.z, translation.x,
+                       m.b1 * scaling.x, m.b2 * scaling.y, m.b3 * scaling.z, translation.y,
+                       m.c1 * scaling.x, m.c2 * scaling.y, m.c3 * scaling.z, translation.z,
+                       0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Matrix4x4 {
+        Matrix4x4::new(self.a1, self.b1, self.c1, self.d1,
+                       self.a2, self.b2, self.c2, self.d2,
+                       self.a3, self.b3, self.c3, self.d3,
+                       self.a4, self.b4, self.c4, self.d4)
+    }
+
+    /// Returns the determinant of the matrix.
+    pub fn determinant(&self) -> f32 {
+        let (s, c) = self.sub_determinants();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix4x4> {
+        let (s, c) = self.sub_determinants();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == 0.0 {
+            return None;
+        }
+
+        let inv = 1.0 / det;
+        Some(Matrix4x4::new(
+            (self.b2 * c[5] - self.b3 * c[4] + self.b4 * c[3]) * inv,
+            (-self.a2 * c[5] + self.a3 * c[4] - self.a4 * c[3]) * inv,
+            (self.d2 * s[5] - self.d3 * s[4] + self.d4 * s[3]) * inv,
+            (-self.c2 * s[5] + self.c3 * s[4] - self.c4 * s[3]) * inv,
+
+            (-self.b1 * c[5] + self.b3 * c[2] - self.b4 * c[1]) * inv,
+            (self.a1 * c[5] - self.a3 * c[2] + self.a4 * c[1]) * inv,
+            (-self.d1 * s[5] + self.d3 * s[2] - self.d4 * s[1]) * inv,
+            (self.c1 * s[5] - self.c3 * s[2] + self.c4 * s[1]) * inv,
+
+            (self.b1 * c[4] - self.b2 * c[2] + self.b4 * c[0]) * inv,
+            (-self.a1 * c[4] + self.a2 * c[2] - self.a4 * c[0]) * inv,
+            (self.d1 * s[4] - self.d2 * s[2] + self.d4 * s[0]) * inv,
+            (-self.c1 * s[4] + self.c2 * s[2] - self.c4 * s[0]) * inv,
+
+            (-self.b1 * c[3] + self.b2 * c[1] - self.b3 * c[0]) * inv,
+            (self.a1 * c[3] - self.a2 * c[1] + self.a3 * c[0]) * inv,
+            (-self.d1 * s[3] + self.d2 * s[1] - self.d3 * s[0]) * inv,
+            (self.c1 * s[3] - self.c2 * s[1] + self.c3 * s[0]) * inv))
+    }
+
+    /// Decomposes the matrix into its scaling, rotation and translation components, in that
+    /// order. Equivalent to Assimp's `aiDecomposeMatrix`.
+    ///
+    /// The matrix must not contain any shearing or perspective components.
+    pub fn decompose(&self) -> (Vector3D, Quaternion, Vector3D) {
+        let translation = Vector3D::new(self.a4, self.b4, self.c4);
+
+        // Columns of the upper 3x3 part hold the scaled basis vectors
+        let cols = [Vector3D::new(self.a1, self.b1, self.c1),
+                    Vector3D::new(self.a2, self.b2, self.c2),
+                    Vector3D::new(self.a3, self.b3, self.c3)];
+
+        let mut scaling = Vector3D::new(cols[0].length(), cols[1].length(), cols[2].length());
+
+        // A negative determinant means the basis was mirrored
+        if self.determinant() < 0.0 {
+            scaling = -scaling;
+        }
+
+        let x = if scaling.x != 0.0 { cols[0] / scaling.x } else { cols[0] };
+        let y = if scaling.y != 0.0 { cols[1] / scaling.y } else { cols[1] };
+        let z = if scaling.z != 0.0 { cols[2] / scaling.z } else { cols[2] };
+
+        let rotation = Quaternion::from_matrix(&Matrix3x3::new(x.x, y.x, z.x,
+                                                               x.y, y.y, z.y,
+                                                               x.z, y.z, z.z));
+        (scaling, rotation, translation)
+    }
+
+    /// 2x2 sub-determinants of the upper and lower halves of the matrix, shared by `determinant`
+    /// and `inverse`.
+    fn sub_determinants(&self) -> ([f32; 6], [f32; 6]) {
+        let s = [self.a1 * self.b2 - self.b1 * self.a2,
+                 self.a1 * self.b3 - self.b1 * self.a3,
+                 self.a1 * self.b4 - self.b1 * self.a4,
+                 self.a2 * self.b3 - self.b2 * self.a3,
+                 self.a2 * self.b4 - self.b2 * self.a4,
+                 self.a3 * self.b4 - self.b3 * self.a4];
+        let c = [self.c1 * self.d2 - self.d1 * self.c2,
+                 self.c1 * self.d3 - self.d1 * self.c3,
+                 self.c1 * self.d4 - self.d1 * self.c4,
+                 self.c2 * self.d3 - self.d2 * self.c3,
+                 self.c2 * self.d4 - self.d2 * self.c4,
+                 self.c3 * self.d4 - self.d3 * self.c4];
+        (s, c)
+    }
+}
+
+impl Add for Matrix4x4 {
+    type Output = Matrix4x4;
+    fn add(self, m: Matrix4x4) -> Matrix4x4 {
+        Matrix4x4::new(self.a1 + m.a1, self.a2 + m.a2, self.a3 + m.a3, self.a4 + m.a4,
+                       self.b1 + m.b1, self.b2 + m.b2, self.b3 + m.b3, self.b4 + m.b4,
+                       self.c1 + m.c1, self.c2 + m.c2, self.c3 + m.c3, self.c4 + m.c4,
+                       self.d1 + m.d1, self.d2 + m.d2, self.d3 + m.d3, self.d4 + m.d4)
+    }
+}
+
+impl Sub for Matrix4x4 {
+    type Output = Matrix4x4;
+    fn sub(self, m: Matrix4x4) -> Matrix4x4 {
+        Matrix4x4::new(self.a1 - m.a1, self.a2 - m.a2, self.a3 - m.a3, self.a4 - m.a4,
+                       self.b1 - m.b1, self.b2 - m.b2, self.b3 - m.b3, self.b4 - m.b4,
+                       self.c1 - m.c1, self.c2 - m.c2, self.c3 - m.c3, self.c4 - m.c4,
+                       self.d1 - m.d1, self.d2 - m.d2, self.d3 - m.d3, self.d4 - m.d4)
+    }
+}
+
+impl Neg for Matrix4x4 {
+    type Output = Matrix4x4;
+    fn neg(self) -> Matrix4x4 {
+        Matrix4x4::new(-self.a1, -self.a2, -self.a3, -self.a4,
+                       -self.b1, -self.b2, -self.b3, -self.b4,
+                       -self.c1, -self.c2, -self.c3, -self.c4,
+                       -self.d1, -self.d2, -self.d3, -self.d4)
+    }
+}
+
+impl Mul for Matrix4x4 {
+    type Output = Matrix4x4;
+    fn mul(self, m: Matrix4x4) -> Matrix4x4 {
+        Matrix4x4::new(
+            m.a1 * self.a1 + m.b1 * self.a2 + m.c1 * self.a3 + m.d1 * self.a4,
+            m.a2 * self.a1 + m.b2 * self.a2 + m.c2 * self.a3 + m.d2 * self.a4,
+            m.a3 * self.a1 + m.b3 * self.a2 + m.c3 * self.a3 + m.d3 * self.a4,
+            m.a4 * self.a1 + m.b4 * self.a2 + m.c4 * self.a3 + m.d4 * self.a4,
+            m.a1 * self.b1 + m.b1 * self.b2 + m.c1 * self.b3 + m.d1 * self.b4,
+            m.a2 * self.b1 + m.b2 * self.b2 + m.c2 * self.b3 + m.d2 * self.b4,
+            m.a3 * self.b1 + m.b3 * self.b2 + m.c3 * self.b3 + m.d3 * self.b4,
+            m.a4 * self.b1 + m.b4 * self.b2 + m.c4 * self.b3 + m.d4 * self.b4,
+            m.a1 * self.c1 + m.b1 * self.c2 + m.c1 * self.c3 + m.d1 * self.c4,
+            m.a2 * self.c1 + m.b2 * self.c2 + m.c2 * self.c3 + m.d2 * self.c4,
+            m.a3 * self.c1 + m.b3 * self.c2 + m.c3 * self.c3 + m.d3 * self.c4,
+            m.a4 * self.c1 + m.b4 * self.c2 + m.c4 * self.c3 + m.d4 * self.c4,
+            m.a1 * self.d1 + m.b1 * self.d2 + m.c1 * self.d3 + m.d1 * self.d4,
+            m.a2 * self.d1 + m.b2 * self.d2 + m.c2 * self.d3 + m.d2 * self.d4,
+            m.a3 * self.d1 + m.b3 * self.d2 + m.c3 * self.d3 + m.d3 * self.d4,
+            m.a4 * self.d1 + m.b4 * self.d2 + m.c4 * self.d3 + m.d4 * self.d4)
+    }
+}
+
+/// Transforms a point by the matrix, including translation.
+impl Mul<Vector3D> for Matrix4x4 {
+    type Output = Vector3D;
+    fn mul(self, v: Vector3D) -> Vector3D {
+        Vector3D::new(self.a1 * v.x + self.a2 * v.y + self.a3 * v.z + self.a4,
+                       self.b1 * v.x + self.b2 * v.y + self.b3 * v.z + self.b4,
+                       self.c1 * v.x + self.c2 * v.y + self.c3 * v.z + self.c4)
+    }
+}
+
+impl From<Matrix3x3> for Matrix4x4 {
+    fn from(m: Matrix3x3) -> Matrix4x4 {
+        Matrix4x4::new(m.a1, m.a2, m.a3, 0.0,
+                       m.b1, m.b2, m.b3, 0.0,
+                       m.c1, m.c2, m.c3, 0.0,
+                       0.0, 0.0, 0.0, 1.0)
+    }
 }
 
 #[cfg(feature = "cgmath")]
@@ -41,3 +252,111 @@ impl From<Matrix4x4> for Matrix4<f32> {
                      mat.a4, mat.b4, mat.c4, mat.d4)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Mat4> for Matrix4x4 {
+    fn from(mat: Mat4) -> Matrix4x4 {
+        let c = mat.to_cols_array_2d();
+        Matrix4x4::new(c[0][0], c[1][0], c[2][0], c[3][0],
+                       c[0][1], c[1][1], c[2][1], c[3][1],
+                       c[0][2], c[1][2], c[2][2], c[3][2],
+                       c[0][3], c[1][3], c[2][3], c[3][3])
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Matrix4x4> for Mat4 {
+    fn from(mat: Matrix4x4) -> Mat4 {
+        Mat4::from_cols_array_2d(&[[mat.a1, mat.b1, mat.c1, mat.d1],
+                                   [mat.a2, mat.b2, mat.c2, mat.d2],
+                                   [mat.a3, mat.b3, mat.c3, mat.d3],
+                                   [mat.a4, mat.b4, mat.c4, mat.d4]])
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Matrix4<f32>> for Matrix4x4 {
+    fn from(mat: na::Matrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(mat[(0, 0)], mat[(0, 1)], mat[(0, 2)], mat[(0, 3)],
+                       mat[(1, 0)], mat[(1, 1)], mat[(1, 2)], mat[(1, 3)],
+                       mat[(2, 0)], mat[(2, 1)], mat[(2, 2)], mat[(2, 3)],
+                       mat[(3, 0)], mat[(3, 1)], mat[(3, 2)], mat[(3, 3)])
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Matrix4x4> for na::Matrix4<f32> {
+    fn from(mat: Matrix4x4) -> na::Matrix4<f32> {
+        na::Matrix4::new(mat.a1, mat.a2, mat.a3, mat.a4,
+                         mat.b1, mat.b2, mat.b3, mat.b4,
+                         mat.c1, mat.c2, mat.c3, mat.c4,
+                         mat.d1, mat.d2, mat.d3, mat.d4)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::RowMatrix4<f32>> for Matrix4x4 {
+    fn from(mat: mint::RowMatrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(mat.x.x, mat.x.y, mat.x.z, mat.x.w,
+                       mat.y.x, mat.y.y, mat.y.z, mat.y.w,
+                       mat.z.x, mat.z.y, mat.z.z, mat.z.w,
+                       mat.w.x, mat.w.y, mat.w.z, mat.w.w)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix4x4> for mint::RowMatrix4<f32> {
+    fn from(mat: Matrix4x4) -> mint::RowMatrix4<f32> {
+        mint::RowMatrix4 {
+            x: mint::Vector4 { x: mat.a1, y: mat.a2, z: mat.a3, w: mat.a4 },
+            y: mint::Vector4 { x: mat.b1, y: mat.b2, z: mat.b3, w: mat.b4 },
+            z: mint::Vector4 { x: mat.c1, y: mat.c2, z: mat.c3, w: mat.c4 },
+            w: mint::Vector4 { x: mat.d1, y: mat.d2, z: mat.d3, w: mat.d4 },
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::ColumnMatrix4<f32>> for Matrix4x4 {
+    fn from(mat: mint::ColumnMatrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(mat.x.x, mat.y.x, mat.z.x, mat.w.x,
+                       mat.x.y, mat.y.y, mat.z.y, mat.w.y,
+                       mat.x.z, mat.y.z, mat.z.z, mat.w.z,
+                       mat.x.w, mat.y.w, mat.z.w, mat.w.w)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Matrix4x4> for mint::ColumnMatrix4<f32> {
+    fn from(mat: Matrix4x4) -> mint::ColumnMatrix4<f32> {
+        mint::ColumnMatrix4 {
+            x: mint::Vector4 { x: mat.a1, y: mat.b1, z: mat.c1, w: mat.d1 },
+            y: mint::Vector4 { x: mat.a2, y: mat.b2, z: mat.c2, w: mat.d2 },
+            z: mint::Vector4 { x: mat.a3, y: mat.b3, z: mat.c3, w: mat.d3 },
+            w: mint::Vector4 { x: mat.a4, y: mat.b4, z: mat.c4, w: mat.d4 },
+        }
+    }
+}
+
+/// Serialized as an array of rows, matching Assimp's row-major layout.
+#[cfg(feature = "serde")]
+impl Serialize for Matrix4x4 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [[self.a1, self.a2, self.a3, self.a4],
+         [self.b1, self.b2, self.b3, self.b4],
+         [self.c1, self.c2, self.c3, self.c4],
+         [self.d1, self.d2, self.d3, self.d4]].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Matrix4x4 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Matrix4x4, D::Error> {
+        <[[f32; 4]; 4]>::deserialize(deserializer).map(|r| {
+            Matrix4x4::new(r[0][0], r[0][1], r[0][2], r[0][3],
+                           r[1][0], r[1][1], r[1][2], r[1][3],
+                           r[2][0], r[2][1], r[2][2], r[2][3],
+                           r[3][0], r[3][1], r[3][2], r[3][3])
+        })
+    }
+}
@@ -1,9 +1,20 @@
 //! The `math` module contains definitions of primitive math types.
 //!
-//! Not really anything useful here. Conversion traits are implemented on each type to convert
-//! into/from the much more useful `cgmath` types.
+//! The types implement the basic arithmetic operators and the helpers Assimp provides in C++
+//! (inverse, transpose, decomposition, quaternion interpolation, ...), which is enough to work
+//! with node transforms and animation keys. For anything more involved, conversion traits are
+//! implemented on each type to convert into/from the much more useful `cgmath` types.
 //! e.g. `Matrix3x3` converts to/from `cgmath::Matrix3<f32>`.
+//!
+//! Conversions for `glam`, `nalgebra` and `mint` are available in the same way behind the
+//! features of the same name. Assimp matrices are row-major, so the conversions take care of
+//! transposing into the column-major layout used by those crates.
+//!
+//! With the `serde` feature enabled every type implements `Serialize`/`Deserialize`. Vectors and
+//! colors are written as plain arrays, matrices as an array of rows and quaternions as a
+//! `{ w, x, y, z }` map.
 
+pub use self::aabb::{Aabb, BoundingSphere};
 pub use self::color3::Color3D;
 pub use self::color4::Color4D;
 pub use self::matrix3::Matrix3x3;
@@ -12,6 +23,7 @@ pub use self::quaternion::Quaternion;
 pub use self::vector2::Vector2D;
 pub use self::vector3::Vector3D;
 
+pub mod aabb;
 pub mod color3;
 pub mod color4;
 pub mod matrix3;
@@ -1,7 +1,19 @@
 #[cfg(feature = "cgmath")]
 use cgmath::Quaternion as CgQuaternion;
+#[cfg(feature = "glam")]
+use glam::Quat;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiQuaternion;
 
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Matrix3x3, Vector3D};
+
 define_type! {
     /// Quaternion docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -12,6 +24,144 @@ impl Quaternion {
     pub fn new(w: f32, x: f32, y: f32 ,z: f32) -> Quaternion {
         Quaternion(AiQuaternion { w: w, x: x, y: y, z: z })
     }
+
+    /// Returns the identity rotation.
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`. The axis must be normalized.
+    pub fn from_axis_angle(axis: Vector3D, angle: f32) -> Quaternion {
+        let half = angle * 0.5;
+        let s = half.sin();
+        Quaternion::new(half.cos(), axis.x * s, axis.y * s, axis.z * s)
+    }
+
+    /// Creates a quaternion from a rotation matrix. The matrix must be orthonormal.
+    pub fn from_matrix(m: &Matrix3x3) -> Quaternion {
+        let t = m.a1 + m.b2 + m.c3;
+        if t > 0.0 {
+            let s = (1.0 + t).sqrt() * 2.0;
+            Quaternion::new(0.25 * s,
+                            (m.c2 - m.b3) / s,
+                            (m.a3 - m.c1) / s,
+                            (m.b1 - m.a2) / s)
+        } else if m.a1 > m.b2 && m.a1 > m.c3 {
+            let s = (1.0 + m.a1 - m.b2 - m.c3).sqrt() * 2.0;
+            Quaternion::new((m.c2 - m.b3) / s,
+                            0.25 * s,
+                            (m.b1 + m.a2) / s,
+                            (m.a3 + m.c1) / s)
+        } else if m.b2 > m.c3 {
+            let s = (1.0 + m.b2 - m.a1 - m.c3).sqrt() * 2.0;
+            Quaternion::new((m.a3 - m.c1) / s,
+                            (m.b1 + m.a2) / s,
+                            0.25 * s,
+                            (m.c2 + m.b3) / s)
+        } else {
+            let s = (1.0 + m.c3 - m.a1 - m.b2).sqrt() * 2.0;
+            Quaternion::new((m.b1 - m.a2) / s,
+                            (m.a3 + m.c1) / s,
+                            (m.c2 + m.b3) / s,
+                            0.25 * s)
+        }
+    }
+
+    /// Returns the dot product of two quaternions.
+    pub fn dot(&self, other: Quaternion) -> f32 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns a unit-length copy of the quaternion. A zero quaternion is returned unchanged.
+    pub fn normalize(&self) -> Quaternion {
+        let len = self.dot(*self).sqrt();
+        if len > 0.0 {
+            let inv = 1.0 / len;
+            Quaternion::new(self.w * inv, self.x * inv, self.y * inv, self.z * inv)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the conjugate of the quaternion. For unit quaternions this is the inverse rotation.
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Spherical linear interpolation between two rotations, equivalent to Assimp's
+    /// `aiQuaternion::Interpolate`. `factor` is in the range [0, 1].
+    pub fn slerp(&self, end: Quaternion, factor: f32) -> Quaternion {
+        // Take the shortest path around the sphere
+        let mut cosom = self.dot(end);
+        let end = if cosom < 0.0 {
+            cosom = -cosom;
+            -end
+        } else {
+            end
+        };
+
+        let (sclp, sclq) = if 1.0 - cosom > 0.0001 {
+            let omega = cosom.acos();
+            let sinom = omega.sin();
+            (((1.0 - factor) * omega).sin() / sinom, (factor * omega).sin() / sinom)
+        } else {
+            // Quaternions are very close, linear interpolation is good enough
+            (1.0 - factor, factor)
+        };
+
+        Quaternion::new(sclp * self.w + sclq * end.w,
+                        sclp * self.x + sclq * end.x,
+                        sclp * self.y + sclq * end.y,
+                        sclp * self.z + sclq * end.z)
+    }
+
+    /// Returns the rotation matrix for this quaternion.
+    pub fn to_matrix(&self) -> Matrix3x3 {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+        Matrix3x3::new(1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w),
+                       2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w),
+                       2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y))
+    }
+
+    /// Rotates a vector by this quaternion. The quaternion must be normalized.
+    pub fn rotate(&self, v: Vector3D) -> Vector3D {
+        let q = *self * Quaternion::new(0.0, v.x, v.y, v.z) * self.conjugate();
+        Vector3D::new(q.x, q.y, q.z)
+    }
+}
+
+/// Component-wise addition.
+impl Add for Quaternion {
+    type Output = Quaternion;
+    fn add(self, o: Quaternion) -> Quaternion {
+        Quaternion::new(self.w + o.w, self.x + o.x, self.y + o.y, self.z + o.z)
+    }
+}
+
+/// Component-wise subtraction.
+impl Sub for Quaternion {
+    type Output = Quaternion;
+    fn sub(self, o: Quaternion) -> Quaternion {
+        Quaternion::new(self.w - o.w, self.x - o.x, self.y - o.y, self.z - o.z)
+    }
+}
+
+/// Quaternion multiplication. The result applies `rhs` first, then `self`.
+impl Mul for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, o: Quaternion) -> Quaternion {
+        Quaternion::new(self.w * o.w - self.x * o.x - self.y * o.y - self.z * o.z,
+                        self.w * o.x + self.x * o.w + self.y * o.z - self.z * o.y,
+                        self.w * o.y + self.y * o.w + self.z * o.x - self.x * o.z,
+                        self.w * o.z + self.z * o.w + self.x * o.y - self.y * o.x)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+    fn neg(self) -> Quaternion {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
 }
 
 #[cfg(feature = "cgmath")]
@@ -27,3 +177,84 @@ impl From<Quaternion> for CgQuaternion<f32> {
         CgQuaternion::new(q.w, q.x, q.y, q.z)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Quat> for Quaternion {
+    fn from(q: Quat) -> Quaternion {
+        Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Quaternion> for Quat {
+    fn from(q: Quaternion) -> Quat {
+        Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Quaternion<f32>> for Quaternion {
+    fn from(q: na::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(q.w, q.i, q.j, q.k)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Quaternion> for na::Quaternion<f32> {
+    fn from(q: Quaternion) -> na::Quaternion<f32> {
+        na::Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::UnitQuaternion<f32>> for Quaternion {
+    fn from(q: na::UnitQuaternion<f32>) -> Quaternion {
+        Quaternion::from(q.into_inner())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Quaternion> for na::UnitQuaternion<f32> {
+    fn from(q: Quaternion) -> na::UnitQuaternion<f32> {
+        na::UnitQuaternion::from_quaternion(q.into())
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Quaternion<f32>> for Quaternion {
+    fn from(q: mint::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(q.s, q.v.x, q.v.y, q.v.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Quaternion> for mint::Quaternion<f32> {
+    fn from(q: Quaternion) -> mint::Quaternion<f32> {
+        mint::Quaternion { v: mint::Vector3 { x: q.x, y: q.y, z: q.z }, s: q.w }
+    }
+}
+
+/// Serialized as a `{ w, x, y, z }` map, so the component order is never ambiguous.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Quaternion")]
+struct QuaternionRepr {
+    w: f32,
+    x: f32,
+    y: f32,
+    z: f32
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Quaternion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        QuaternionRepr { w: self.w, x: self.x, y: self.y, z: self.z }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Quaternion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Quaternion, D::Error> {
+        QuaternionRepr::deserialize(deserializer).map(|q| Quaternion::new(q.w, q.x, q.y, q.z))
+    }
+}
@@ -1,7 +1,17 @@
 #[cfg(feature = "cgmath")]
 use cgmath::{Point2, Vector2};
+#[cfg(feature = "glam")]
+use glam::Vec2;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiVector2D;
 
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
 define_type! {
     /// Vector2D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
@@ -12,6 +22,62 @@ impl Vector2D {
     pub fn new(x: f32, y: f32) -> Vector2D {
         Vector2D(AiVector2D { x: x, y: y })
     }
+
+    /// Returns the dot product of two vectors.
+    pub fn dot(&self, other: Vector2D) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the squared length of the vector.
+    pub fn square_length(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    /// Returns the length of the vector.
+    pub fn length(&self) -> f32 {
+        self.square_length().sqrt()
+    }
+
+    /// Returns a unit-length copy of the vector. A zero vector is returned unchanged.
+    pub fn normalize(&self) -> Vector2D {
+        let len = self.length();
+        if len > 0.0 { *self / len } else { *self }
+    }
+}
+
+impl Add for Vector2D {
+    type Output = Vector2D;
+    fn add(self, rhs: Vector2D) -> Vector2D {
+        Vector2D::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector2D {
+    type Output = Vector2D;
+    fn sub(self, rhs: Vector2D) -> Vector2D {
+        Vector2D::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<f32> for Vector2D {
+    type Output = Vector2D;
+    fn mul(self, rhs: f32) -> Vector2D {
+        Vector2D::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Div<f32> for Vector2D {
+    type Output = Vector2D;
+    fn div(self, rhs: f32) -> Vector2D {
+        Vector2D::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl Neg for Vector2D {
+    type Output = Vector2D;
+    fn neg(self) -> Vector2D {
+        Vector2D::new(-self.x, -self.y)
+    }
 }
 
 impl From<[f32; 2]> for Vector2D {
@@ -53,3 +119,88 @@ impl From<Vector2D> for Vector2<f32> {
         Vector2::new(v.x, v.y)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Vec2> for Vector2D {
+    fn from(v: Vec2) -> Vector2D {
+        Vector2D::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector2D> for Vec2 {
+    fn from(v: Vector2D) -> Vec2 {
+        Vec2::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Point2<f32>> for Vector2D {
+    fn from(p: na::Point2<f32>) -> Vector2D {
+        Vector2D::new(p.x, p.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vector2D> for na::Point2<f32> {
+    fn from(v: Vector2D) -> na::Point2<f32> {
+        na::Point2::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Vector2<f32>> for Vector2D {
+    fn from(v: na::Vector2<f32>) -> Vector2D {
+        Vector2D::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vector2D> for na::Vector2<f32> {
+    fn from(v: Vector2D) -> na::Vector2<f32> {
+        na::Vector2::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Point2<f32>> for Vector2D {
+    fn from(p: mint::Point2<f32>) -> Vector2D {
+        Vector2D::new(p.x, p.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vector2D> for mint::Point2<f32> {
+    fn from(v: Vector2D) -> mint::Point2<f32> {
+        mint::Point2 { x: v.x, y: v.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector2<f32>> for Vector2D {
+    fn from(v: mint::Vector2<f32>) -> Vector2D {
+        Vector2D::new(v.x, v.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vector2D> for mint::Vector2<f32> {
+    fn from(v: Vector2D) -> mint::Vector2<f32> {
+        mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+/// Serialized as an `[x, y]` array.
+#[cfg(feature = "serde")]
+impl Serialize for Vector2D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        <[f32; 2]>::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Vector2D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Vector2D, D::Error> {
+        <[f32; 2]>::deserialize(deserializer).map(Vector2D::from)
+    }
+}
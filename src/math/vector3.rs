@@ -1,12 +1,23 @@
 #[cfg(feature = "cgmath")]
 use cgmath::{Point3, Vector3};
+#[cfg(feature = "glam")]
+use glam::Vec3;
+#[cfg(feature = "mint")]
+use mint;
+#[cfg(feature = "nalgebra")]
+use nalgebra as na;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use ffi::AiVector3D;
 
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
 define_type_and_iterator! {
     /// Vector3D docs
     #[derive(Clone, Copy, Debug, PartialEq)]
     struct Vector3D(AiVector3D)
     /// Vector3DIter docs
+    #[derive(Clone)]
     struct Vector3DIter
 }
 
@@ -14,6 +25,69 @@ impl Vector3D {
     pub fn new(x: f32, y: f32, z: f32) -> Vector3D {
         Vector3D(AiVector3D { x: x, y: y, z: z })
     }
+
+    /// Returns the dot product of two vectors.
+    pub fn dot(&self, other: Vector3D) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the cross product of two vectors.
+    pub fn cross(&self, other: Vector3D) -> Vector3D {
+        Vector3D::new(self.y * other.z - self.z * other.y,
+                      self.z * other.x - self.x * other.z,
+                      self.x * other.y - self.y * other.x)
+    }
+
+    /// Returns the squared length of the vector.
+    pub fn square_length(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    /// Returns the length of the vector.
+    pub fn length(&self) -> f32 {
+        self.square_length().sqrt()
+    }
+
+    /// Returns a unit-length copy of the vector. A zero vector is returned unchanged.
+    pub fn normalize(&self) -> Vector3D {
+        let len = self.length();
+        if len > 0.0 { *self / len } else { *self }
+    }
+}
+
+impl Add for Vector3D {
+    type Output = Vector3D;
+    fn add(self, rhs: Vector3D) -> Vector3D {
+        Vector3D::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for Vector3D {
+    type Output = Vector3D;
+    fn sub(self, rhs: Vector3D) -> Vector3D {
+        Vector3D::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Mul<f32> for Vector3D {
+    type Output = Vector3D;
+    fn mul(self, rhs: f32) -> Vector3D {
+        Vector3D::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Div<f32> for Vector3D {
+    type Output = Vector3D;
+    fn div(self, rhs: f32) -> Vector3D {
+        Vector3D::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl Neg for Vector3D {
+    type Output = Vector3D;
+    fn neg(self) -> Vector3D {
+        Vector3D::new(-self.x, -self.y, -self.z)
+    }
 }
 
 impl From<[f32; 3]> for Vector3D {
@@ -55,3 +129,88 @@ impl From<Vector3D> for Vector3<f32> {
         Vector3::new(v.x, v.y, v.z)
     }
 }
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for Vector3D {
+    fn from(v: Vec3) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vector3D> for Vec3 {
+    fn from(v: Vector3D) -> Vec3 {
+        Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Point3<f32>> for Vector3D {
+    fn from(p: na::Point3<f32>) -> Vector3D {
+        Vector3D::new(p.x, p.y, p.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vector3D> for na::Point3<f32> {
+    fn from(v: Vector3D) -> na::Point3<f32> {
+        na::Point3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<na::Vector3<f32>> for Vector3D {
+    fn from(v: na::Vector3<f32>) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vector3D> for na::Vector3<f32> {
+    fn from(v: Vector3D) -> na::Vector3<f32> {
+        na::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Point3<f32>> for Vector3D {
+    fn from(p: mint::Point3<f32>) -> Vector3D {
+        Vector3D::new(p.x, p.y, p.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vector3D> for mint::Point3<f32> {
+    fn from(v: Vector3D) -> mint::Point3<f32> {
+        mint::Point3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector3<f32>> for Vector3D {
+    fn from(v: mint::Vector3<f32>) -> Vector3D {
+        Vector3D::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<Vector3D> for mint::Vector3<f32> {
+    fn from(v: Vector3D) -> mint::Vector3<f32> {
+        mint::Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+/// Serialized as an `[x, y, z]` array.
+#[cfg(feature = "serde")]
+impl Serialize for Vector3D {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        <[f32; 3]>::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Vector3D {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Vector3D, D::Error> {
+        <[f32; 3]>::deserialize(deserializer).map(Vector3D::from)
+    }
+}
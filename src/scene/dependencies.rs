@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, Read};
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::Chars;
+
+use super::scene::Scene;
+
+impl<'a> Scene<'a> {
+    /// Returns the external files needed by the scene imported from `model_path`.
+    ///
+    /// Lists the files the model file refers to, followed by the files returned by
+    /// `texture_dependencies`. The model file is read again to find the files which aren't
+    /// recorded in the scene:
+    ///
+    /// - the material libraries of OBJ files (`mtllib`),
+    /// - the buffers of glTF and binary glTF files (`buffers[].uri`), except data URIs.
+    ///
+    /// Files referenced by other formats, e.g. MD3 skins or Ogre material files, aren't listed.
+    /// Each file is listed once, as written in the model, use `TextureResolver::resolve` to find
+    /// the actual files.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use assimp::Importer;
+    /// use assimp::scene::{ResolvedTexture, TextureResolver};
+    ///
+    /// let importer = Importer::new();
+    /// let scene = importer.read_file("models/house.obj").unwrap();
+    ///
+    /// let resolver = TextureResolver::new("models/house.obj");
+    /// for reference in scene.dependencies("models/house.obj").unwrap() {
+    ///     if let ResolvedTexture::File(path) = resolver.resolve(&reference) {
+    ///         println!("{}", path.display());
+    ///     }
+    /// }
+    /// ```
+    pub fn dependencies<P: AsRef<Path>>(&self, model_path: P) -> io::Result<Vec<String>> {
+        let mut seen = HashSet::new();
+        let mut dependencies = Vec::new();
+        let files = referenced_files(model_path.as_ref())?;
+        for file in files.into_iter().chain(self.texture_dependencies()) {
+            if seen.insert(file.clone()) {
+                dependencies.push(file);
+            }
+        }
+        Ok(dependencies)
+    }
+}
+
+// Reads the files referenced by a model file, other than its textures.
+fn referenced_files(path: &Path) -> io::Result<Vec<String>> {
+    let extension = path.extension().and_then(|x| x.to_str()).unwrap_or("").to_ascii_lowercase();
+    match &*extension {
+        "obj" => {
+            let mut text = String::new();
+            File::open(path)?.read_to_string(&mut text)?;
+            Ok(obj_material_libraries(&text))
+        }
+        "gltf" => {
+            let mut text = String::new();
+            File::open(path)?.read_to_string(&mut text)?;
+            Ok(gltf_buffers(&text))
+        }
+        "glb" => {
+            let mut data = Vec::new();
+            File::open(path)?.read_to_end(&mut data)?;
+            Ok(glb_json(&data).map(gltf_buffers).unwrap_or_default())
+        }
+        _ => Ok(Vec::new())
+    }
+}
+
+// Like Assimp, takes the rest of an `mtllib` line as a single file name.
+fn obj_material_libraries(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| line.starts_with("mtllib") && line[6..].starts_with(char::is_whitespace))
+        .map(|line| line[6..].trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+// Returns the JSON chunk of a binary glTF file. Versions 1 and 2 both store its length at
+// offset 12 and its data at offset 20.
+fn glb_json(data: &[u8]) -> Option<&str> {
+    if data.len() < 20 || &data[0..4] != b"glTF" {
+        return None;
+    }
+    let len = u32::from_le_bytes([data[12], data[13], data[14], data[15]]) as usize;
+    data.get(20..20 + len).and_then(|x| ::std::str::from_utf8(x).ok())
+}
+
+// glTF 2 stores buffers in an array, glTF 1 in an object keyed by identifier.
+fn gltf_buffers(text: &str) -> Vec<String> {
+    let buffers = match Json::parse(text) {
+        Some(Json::Object(root)) => root.into_iter().find(|x| x.0 == "buffers").map(|x| x.1),
+        _ => None
+    };
+    let buffers = match buffers {
+        Some(Json::Array(buffers)) => buffers,
+        Some(Json::Object(buffers)) => buffers.into_iter().map(|x| x.1).collect(),
+        _ => Vec::new()
+    };
+    buffers.into_iter()
+        .filter_map(|buffer| match buffer {
+            Json::Object(fields) => fields.into_iter().find(|x| x.0 == "uri").map(|x| x.1),
+            _ => None
+        })
+        .filter_map(|uri| match uri {
+            Json::String(uri) => Some(uri),
+            _ => None
+        })
+        .filter(|uri| !uri.is_empty() && !uri.starts_with("data:"))
+        .collect()
+}
+
+// Just enough JSON to read the buffers of glTF files. Numbers and literals aren't kept.
+enum Json {
+    Object(Vec<(String, Json)>),
+    Array(Vec<Json>),
+    String(String),
+    Other
+}
+
+impl Json {
+    fn parse(text: &str) -> Option<Json> {
+        let mut chars = text.chars().peekable();
+        let value = Json::value(&mut chars)?;
+        skip_whitespace(&mut chars);
+        if chars.next().is_none() { Some(value) } else { None }
+    }
+
+    fn value(chars: &mut Peekable<Chars>) -> Option<Json> {
+        skip_whitespace(chars);
+        match *chars.peek()? {
+            '{' => {
+                chars.next();
+                let mut fields = Vec::new();
+                skip_whitespace(chars);
+                if chars.peek() == Some(&'}') {
+                    chars.next();
+                    return Some(Json::Object(fields));
+                }
+                loop {
+                    skip_whitespace(chars);
+                    let key = Json::string(chars)?;
+                    skip_whitespace(chars);
+                    if chars.next()? != ':' {
+                        return None;
+                    }
+                    fields.push((key, Json::value(chars)?));
+                    skip_whitespace(chars);
+                    match chars.next()? {
+                        ',' => (),
+                        '}' => return Some(Json::Object(fields)),
+                        _ => return None
+                    }
+                }
+            }
+            '[' => {
+                chars.next();
+                let mut items = Vec::new();
+                skip_whitespace(chars);
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                    return Some(Json::Array(items));
+                }
+                loop {
+                    items.push(Json::value(chars)?);
+                    skip_whitespace(chars);
+                    match chars.next()? {
+                        ',' => (),
+                        ']' => return Some(Json::Array(items)),
+                        _ => return None
+                    }
+                }
+            }
+            '"' => Json::string(chars).map(Json::String),
+            _ => {
+                let mut empty = true;
+                while chars.peek().map_or(false, |&c| c.is_ascii_alphanumeric() || "+-.".contains(c)) {
+                    chars.next();
+                    empty = false;
+                }
+                if empty { None } else { Some(Json::Other) }
+            }
+        }
+    }
+
+    fn string(chars: &mut Peekable<Chars>) -> Option<String> {
+        if chars.next()? != '"' {
+            return None;
+        }
+        let mut string = String::new();
+        loop {
+            match chars.next()? {
+                '"' => return Some(string),
+                '\\' => match chars.next()? {
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'n' => string.push('\n'),
+                    'r' => string.push('\r'),
+                    't' => string.push('\t'),
+                    'u' => {
+                        let code: String = chars.by_ref().take(4).collect();
+                        let code = u32::from_str_radix(&code, 16).ok()?;
+                        // Surrogate pairs aren't combined, file names rarely need them
+                        string.push(::std::char::from_u32(code).unwrap_or('\u{fffd}'));
+                    }
+                    c => string.push(c)
+                },
+                c => string.push(c)
+            }
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+        chars.next();
+    }
+}
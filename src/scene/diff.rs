@@ -0,0 +1,709 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use math::{Matrix4x4, Vector3D};
+
+use super::animation::{Animation, NodeAnim};
+use super::material::{Material, MaterialProperty, PropertyValue};
+use super::mesh::Mesh;
+use super::node::Node;
+use super::scene::Scene;
+
+/// Tolerances of `Scene::diff`.
+///
+/// Values differing by no more than the tolerance are considered equal.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DiffOptions {
+    /// Tolerance of the elements of node transformations. Default: 1e-4
+    pub transform_tolerance: f32,
+    /// Tolerance of vertex attributes. Default: 1e-4
+    pub vertex_tolerance: f32,
+    /// Tolerance of numeric material properties. Default: 1e-4
+    pub material_tolerance: f32,
+    /// Tolerance of animation durations, and of the times and values of keys. Default: 1e-4
+    pub animation_tolerance: f32
+}
+
+impl Default for DiffOptions {
+    fn default() -> DiffOptions {
+        DiffOptions::with_tolerance(1e-4)
+    }
+}
+
+impl DiffOptions {
+    /// Returns options using the same tolerance for all values.
+    pub fn with_tolerance(tolerance: f32) -> DiffOptions {
+        DiffOptions {
+            transform_tolerance: tolerance,
+            vertex_tolerance: tolerance,
+            material_tolerance: tolerance,
+            animation_tolerance: tolerance
+        }
+    }
+}
+
+/// A count compared by `Difference::MeshCount`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MeshCount {
+    Vertices,
+    Faces,
+    /// Total number of indices of all faces.
+    Indices,
+    Bones
+}
+
+/// A per-vertex attribute of a mesh.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MeshAttribute {
+    Positions,
+    Normals,
+    Tangents,
+    Bitangents,
+    /// Texture coordinates of the given channel.
+    TextureCoords(usize),
+    /// Vertex colors of the given set.
+    Colors(usize)
+}
+
+/// A kind of animation key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KeyType {
+    Position,
+    Rotation,
+    Scaling
+}
+
+/// Identifies a material property.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PropertyId {
+    pub key: String,
+    pub semantic: u32,
+    pub index: u32
+}
+
+/// A difference between two scenes.
+///
+/// Nodes are identified by their path from the root node in the old scene, e.g.
+/// `"root/body/arm"`. Meshes and materials are identified by their index, animations by their
+/// name.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Difference {
+    NodeAdded { path: String },
+    NodeRemoved { path: String },
+    /// The node was renamed to `name`.
+    NodeRenamed { path: String, name: String },
+    /// The transformations of the node differ by up to `max_error` in one of their elements.
+    NodeTransform { path: String, max_error: f32 },
+    /// The node references different meshes.
+    NodeMeshes { path: String, old: Vec<u32>, new: Vec<u32> },
+
+    MeshAdded { mesh: usize },
+    MeshRemoved { mesh: usize },
+    MeshCount { mesh: usize, count: MeshCount, old: usize, new: usize },
+    MeshMaterial { mesh: usize, old: u32, new: u32 },
+    /// `changed` faces have different indices. Only compared if the meshes have the same
+    /// number of faces.
+    MeshFaces { mesh: usize, changed: usize },
+    MeshAttributeAdded { mesh: usize, attribute: MeshAttribute },
+    MeshAttributeRemoved { mesh: usize, attribute: MeshAttribute },
+    /// The values of the attribute differ by up to `max_error` in one of their components. Only
+    /// compared if the meshes have the same number of vertices.
+    MeshAttribute { mesh: usize, attribute: MeshAttribute, max_error: f32 },
+
+    MaterialAdded { material: usize },
+    MaterialRemoved { material: usize },
+    MaterialPropertyAdded { material: usize, property: PropertyId },
+    MaterialPropertyRemoved { material: usize, property: PropertyId },
+    /// The values of the property differ. `max_error` is the largest difference between
+    /// elements of numeric values, and `None` if the values can't be compared numerically.
+    MaterialProperty { material: usize, property: PropertyId, max_error: Option<f32> },
+
+    AnimationAdded { animation: String },
+    AnimationRemoved { animation: String },
+    AnimationDuration { animation: String, old: f64, new: f64 },
+    AnimationTicksPerSecond { animation: String, old: f64, new: f64 },
+    ChannelAdded { animation: String, node: String },
+    ChannelRemoved { animation: String, node: String },
+    KeyCount { animation: String, node: String, keys: KeyType, old: usize, new: usize },
+    /// The times or values of the keys differ by up to `max_error`. Only compared if the
+    /// channels have the same number of keys.
+    Keys { animation: String, node: String, keys: KeyType, max_error: f32 }
+}
+
+/// Differences between two scenes.
+///
+/// See `Scene::diff`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SceneDiff {
+    pub differences: Vec<Difference>
+}
+
+impl SceneDiff {
+    /// Returns true if the scenes are equal within the tolerances.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl<'a> Scene<'a> {
+    /// Compares the scene with another one, e.g. the same file imported with different
+    /// settings.
+    ///
+    /// Nodes are matched by name among the children of matching nodes. Children left without
+    /// a match on both sides are paired in order and reported as renamed. Meshes and materials
+    /// are matched by index, animations and their channels by name.
+    ///
+    /// Vertex attributes and animation keys are only compared value by value if their number
+    /// is the same in both scenes, otherwise only the change of count is reported.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use assimp::import::Importer;
+    /// use assimp::scene::DiffOptions;
+    ///
+    /// let importer = Importer::new();
+    /// let old = importer.read_file("examples/box.obj").unwrap();
+    ///
+    /// let mut importer = Importer::new();
+    /// importer.join_identical_vertices(true);
+    /// let new = importer.read_file("examples/box.obj").unwrap();
+    ///
+    /// for difference in &old.diff(&new, &DiffOptions::default()).differences {
+    ///     println!("{}", difference);
+    /// }
+    /// ```
+    pub fn diff(&self, other: &Scene, options: &DiffOptions) -> SceneDiff {
+        let mut differ = Differ { options: options, differences: Vec::new() };
+        if !self.root_node.is_null() && !other.root_node.is_null() {
+            let root = self.root_node();
+            let path = root.name().to_owned();
+            differ.node(&root, &other.root_node(), &path);
+        }
+        differ.meshes(self, other);
+        differ.materials(self, other);
+        differ.animations(self, other);
+        SceneDiff { differences: differ.differences }
+    }
+}
+
+struct Differ<'o> {
+    options: &'o DiffOptions,
+    differences: Vec<Difference>
+}
+
+impl<'o> Differ<'o> {
+    fn node(&mut self, old: &Node, new: &Node, path: &str) {
+        let max_error = matrix_error(&old.transformation(), &new.transformation());
+        if max_error > self.options.transform_tolerance {
+            self.differences.push(Difference::NodeTransform { path: path.to_owned(), max_error: max_error });
+        }
+        if old.meshes() != new.meshes() {
+            self.differences.push(Difference::NodeMeshes {
+                path: path.to_owned(),
+                old: old.meshes().to_vec(),
+                new: new.meshes().to_vec()
+            });
+        }
+
+        let old_children: Vec<Node> = old.child_iter().collect();
+        let new_children: Vec<Node> = new.child_iter().collect();
+        let (pairs, removed, added) = match_names(
+            &old_children.iter().map(|x| x.name()).collect::<Vec<_>>(),
+            &new_children.iter().map(|x| x.name()).collect::<Vec<_>>());
+
+        for (i, j) in pairs {
+            let child_path = format!("{}/{}", path, old_children[i].name());
+            self.node(&old_children[i], &new_children[j], &child_path);
+        }
+        for (&i, &j) in removed.iter().zip(&added) {
+            let child_path = format!("{}/{}", path, old_children[i].name());
+            self.differences.push(Difference::NodeRenamed {
+                path: child_path.clone(),
+                name: new_children[j].name().to_owned()
+            });
+            self.node(&old_children[i], &new_children[j], &child_path);
+        }
+        for &i in removed.iter().skip(added.len()) {
+            self.differences.push(Difference::NodeRemoved {
+                path: format!("{}/{}", path, old_children[i].name())
+            });
+        }
+        for &j in added.iter().skip(removed.len()) {
+            self.differences.push(Difference::NodeAdded {
+                path: format!("{}/{}", path, new_children[j].name())
+            });
+        }
+    }
+
+    fn meshes(&mut self, old: &Scene, new: &Scene) {
+        let old_meshes: Vec<Mesh> = old.mesh_iter().collect();
+        let new_meshes: Vec<Mesh> = new.mesh_iter().collect();
+        for (i, (a, b)) in old_meshes.iter().zip(&new_meshes).enumerate() {
+            self.mesh(i, a, b);
+        }
+        for i in new_meshes.len()..old_meshes.len() {
+            self.differences.push(Difference::MeshRemoved { mesh: i });
+        }
+        for i in old_meshes.len()..new_meshes.len() {
+            self.differences.push(Difference::MeshAdded { mesh: i });
+        }
+    }
+
+    fn mesh(&mut self, index: usize, old: &Mesh, new: &Mesh) {
+        let counts = [
+            (MeshCount::Vertices, old.num_vertices() as usize, new.num_vertices() as usize),
+            (MeshCount::Faces, old.num_faces() as usize, new.num_faces() as usize),
+            (MeshCount::Indices, num_indices(old), num_indices(new)),
+            (MeshCount::Bones, old.num_bones() as usize, new.num_bones() as usize)
+        ];
+        for &(count, a, b) in &counts {
+            if a != b {
+                self.differences.push(Difference::MeshCount { mesh: index, count: count, old: a, new: b });
+            }
+        }
+        if old.material_index() != new.material_index() {
+            self.differences.push(Difference::MeshMaterial {
+                mesh: index,
+                old: old.material_index(),
+                new: new.material_index()
+            });
+        }
+        if old.num_faces() == new.num_faces() {
+            let changed = old.face_iter().zip(new.face_iter())
+                .filter(|&(ref a, ref b)| {
+                    a.num_indices != b.num_indices ||
+                        (0..a.num_indices as isize).any(|i| a[i] != b[i])
+                })
+                .count();
+            if changed > 0 {
+                self.differences.push(Difference::MeshFaces { mesh: index, changed: changed });
+            }
+        }
+
+        let mut attributes = vec![
+            (MeshAttribute::Positions, attribute_values(old, MeshAttribute::Positions),
+             attribute_values(new, MeshAttribute::Positions)),
+            (MeshAttribute::Normals, attribute_values(old, MeshAttribute::Normals),
+             attribute_values(new, MeshAttribute::Normals)),
+            (MeshAttribute::Tangents, attribute_values(old, MeshAttribute::Tangents),
+             attribute_values(new, MeshAttribute::Tangents)),
+            (MeshAttribute::Bitangents, attribute_values(old, MeshAttribute::Bitangents),
+             attribute_values(new, MeshAttribute::Bitangents))
+        ];
+        for channel in 0..old.num_uv_channels().max(new.num_uv_channels()) as usize {
+            let attribute = MeshAttribute::TextureCoords(channel);
+            attributes.push((attribute, attribute_values(old, attribute), attribute_values(new, attribute)));
+        }
+        for set in 0..old.num_color_sets().max(new.num_color_sets()) as usize {
+            let attribute = MeshAttribute::Colors(set);
+            attributes.push((attribute, attribute_values(old, attribute), attribute_values(new, attribute)));
+        }
+
+        let same_vertices = old.num_vertices() == new.num_vertices();
+        for (attribute, a, b) in attributes {
+            match (a, b) {
+                (Some(a), Some(b)) => {
+                    if same_vertices {
+                        let max_error = max_error(&a, &b);
+                        if max_error > self.options.vertex_tolerance {
+                            self.differences.push(Difference::MeshAttribute {
+                                mesh: index,
+                                attribute: attribute,
+                                max_error: max_error
+                            });
+                        }
+                    }
+                }
+                (Some(_), None) => {
+                    self.differences.push(Difference::MeshAttributeRemoved { mesh: index, attribute: attribute });
+                }
+                (None, Some(_)) => {
+                    self.differences.push(Difference::MeshAttributeAdded { mesh: index, attribute: attribute });
+                }
+                (None, None) => ()
+            }
+        }
+    }
+
+    fn materials(&mut self, old: &Scene, new: &Scene) {
+        let old_materials: Vec<Material> = old.material_iter().collect();
+        let new_materials: Vec<Material> = new.material_iter().collect();
+        for (i, (a, b)) in old_materials.iter().zip(&new_materials).enumerate() {
+            self.material(i, a, b);
+        }
+        for i in new_materials.len()..old_materials.len() {
+            self.differences.push(Difference::MaterialRemoved { material: i });
+        }
+        for i in old_materials.len()..new_materials.len() {
+            self.differences.push(Difference::MaterialAdded { material: i });
+        }
+    }
+
+    fn material(&mut self, index: usize, old: &Material, new: &Material) {
+        let old_properties: Vec<MaterialProperty> = old.properties_iter().collect();
+        let new_properties: Vec<MaterialProperty> = new.properties_iter().collect();
+        let old_ids: Vec<PropertyId> = old_properties.iter().map(property_id).collect();
+        let new_ids: Vec<PropertyId> = new_properties.iter().map(property_id).collect();
+
+        for (i, id) in old_ids.iter().enumerate() {
+            match new_ids.iter().position(|x| x == id) {
+                Some(j) => {
+                    let tolerance = self.options.material_tolerance;
+                    if let Some(max_error) = property_error(old_properties[i].value(),
+                                                            new_properties[j].value(), tolerance) {
+                        self.differences.push(Difference::MaterialProperty {
+                            material: index,
+                            property: id.clone(),
+                            max_error: max_error
+                        });
+                    }
+                }
+                None => {
+                    self.differences.push(Difference::MaterialPropertyRemoved {
+                        material: index,
+                        property: id.clone()
+                    });
+                }
+            }
+        }
+        for id in new_ids.iter().filter(|id| !old_ids.contains(id)) {
+            self.differences.push(Difference::MaterialPropertyAdded { material: index, property: id.clone() });
+        }
+    }
+
+    fn animations(&mut self, old: &Scene, new: &Scene) {
+        let old_animations: Vec<Animation> = old.animation_iter().collect();
+        let new_animations: Vec<Animation> = new.animation_iter().collect();
+        let (pairs, removed, added) = match_names(
+            &old_animations.iter().map(|x| x.name.as_ref()).collect::<Vec<_>>(),
+            &new_animations.iter().map(|x| x.name.as_ref()).collect::<Vec<_>>());
+
+        for (i, j) in pairs {
+            self.animation(&old_animations[i], &new_animations[j]);
+        }
+        for i in removed {
+            self.differences.push(Difference::AnimationRemoved {
+                animation: old_animations[i].name.as_ref().to_owned()
+            });
+        }
+        for j in added {
+            self.differences.push(Difference::AnimationAdded {
+                animation: new_animations[j].name.as_ref().to_owned()
+            });
+        }
+    }
+
+    fn animation(&mut self, old: &Animation, new: &Animation) {
+        let name: &str = old.name.as_ref();
+        let tolerance = self.options.animation_tolerance;
+        if value_error(old.duration, new.duration) > tolerance {
+            self.differences.push(Difference::AnimationDuration {
+                animation: name.to_owned(),
+                old: old.duration,
+                new: new.duration
+            });
+        }
+        if value_error(old.ticks_per_second, new.ticks_per_second) > tolerance {
+            self.differences.push(Difference::AnimationTicksPerSecond {
+                animation: name.to_owned(),
+                old: old.ticks_per_second,
+                new: new.ticks_per_second
+            });
+        }
+
+        let old_channels: Vec<NodeAnim> = (0..old.num_channels as usize).filter_map(|i| old.get_node_anim(i)).collect();
+        let new_channels: Vec<NodeAnim> = (0..new.num_channels as usize).filter_map(|i| new.get_node_anim(i)).collect();
+        let (pairs, removed, added) = match_names(
+            &old_channels.iter().map(|x| x.node_name.as_ref()).collect::<Vec<_>>(),
+            &new_channels.iter().map(|x| x.node_name.as_ref()).collect::<Vec<_>>());
+
+        for (i, j) in pairs {
+            for &keys in &[KeyType::Position, KeyType::Rotation, KeyType::Scaling] {
+                self.keys(name, &old_channels[i], &new_channels[j], keys);
+            }
+        }
+        for i in removed {
+            self.differences.push(Difference::ChannelRemoved {
+                animation: name.to_owned(),
+                node: old_channels[i].node_name.as_ref().to_owned()
+            });
+        }
+        for j in added {
+            self.differences.push(Difference::ChannelAdded {
+                animation: name.to_owned(),
+                node: new_channels[j].node_name.as_ref().to_owned()
+            });
+        }
+    }
+
+    fn keys(&mut self, animation: &str, old: &NodeAnim, new: &NodeAnim, keys: KeyType) {
+        let node: &str = old.node_name.as_ref();
+        let (a, b) = (key_values(old, keys), key_values(new, keys));
+        if a.len() != b.len() {
+            self.differences.push(Difference::KeyCount {
+                animation: animation.to_owned(),
+                node: node.to_owned(),
+                keys: keys,
+                old: a.len(),
+                new: b.len()
+            });
+            return;
+        }
+
+        let max_error = a.iter().zip(&b).map(|(a, b)| {
+            let time_error = value_error(a[0] as f64, b[0] as f64);
+            let value_error = if keys == KeyType::Rotation {
+                // q and -q are the same rotation
+                max_error(&a[1..], &b[1..]).min(
+                    a[1..].iter().zip(&b[1..]).map(|(x, y)| value_error(*x as f64, -*y as f64))
+                        .fold(0.0, f32::max))
+            } else {
+                max_error(&a[1..], &b[1..])
+            };
+            time_error.max(value_error)
+        }).fold(0.0, f32::max);
+        if max_error > self.options.animation_tolerance {
+            self.differences.push(Difference::Keys {
+                animation: animation.to_owned(),
+                node: node.to_owned(),
+                keys: keys,
+                max_error: max_error
+            });
+        }
+    }
+}
+
+// Pairs items with the same name, in order. Returns the pairs of indices, and the indices of
+// the unpaired items of each side.
+fn match_names(old: &[&str], new: &[&str]) -> (Vec<(usize, usize)>, Vec<usize>, Vec<usize>) {
+    let mut used = vec![false; new.len()];
+    let mut pairs = Vec::new();
+    let mut removed = Vec::new();
+    for (i, name) in old.iter().enumerate() {
+        match (0..new.len()).find(|&j| !used[j] && new[j] == *name) {
+            Some(j) => {
+                used[j] = true;
+                pairs.push((i, j));
+            }
+            None => removed.push(i)
+        }
+    }
+    let added = (0..new.len()).filter(|&j| !used[j]).collect();
+    (pairs, removed, added)
+}
+
+fn max_error(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| value_error(*x as f64, *y as f64)).fold(0.0, f32::max)
+}
+
+// Returns the difference between two values. NaN only equals NaN, and differs from any number
+// by infinity, since `f32::max` would ignore it.
+fn value_error(x: f64, y: f64) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        if x.is_nan() && y.is_nan() { 0.0 } else { f32::INFINITY }
+    } else if x == y {
+        // Also covers infinities of the same sign, whose difference is NaN
+        0.0
+    } else {
+        (x - y).abs() as f32
+    }
+}
+
+fn matrix_error(a: &Matrix4x4, b: &Matrix4x4) -> f32 {
+    max_error(&[a.a1, a.a2, a.a3, a.a4, a.b1, a.b2, a.b3, a.b4,
+                a.c1, a.c2, a.c3, a.c4, a.d1, a.d2, a.d3, a.d4],
+              &[b.a1, b.a2, b.a3, b.a4, b.b1, b.b2, b.b3, b.b4,
+                b.c1, b.c2, b.c3, b.c4, b.d1, b.d2, b.d3, b.d4])
+}
+
+fn num_indices(mesh: &Mesh) -> usize {
+    mesh.face_iter().map(|face| face.num_indices as usize).sum()
+}
+
+// Returns the components of the attribute of all vertices, or `None` if the mesh doesn't have
+// the attribute.
+fn attribute_values(mesh: &Mesh, attribute: MeshAttribute) -> Option<Vec<f32>> {
+    fn flatten<I: Iterator<Item = Vector3D>>(iter: I) -> Vec<f32> {
+        iter.flat_map(|v| vec![v.x, v.y, v.z]).collect()
+    }
+
+    match attribute {
+        MeshAttribute::Positions => Some(flatten(mesh.vertex_iter())),
+        MeshAttribute::Normals if !mesh.normals.is_null() => Some(flatten(mesh.normal_iter())),
+        MeshAttribute::Tangents if !mesh.tangents.is_null() => Some(flatten(mesh.tangent_iter())),
+        MeshAttribute::Bitangents if !mesh.bitangents.is_null() => Some(flatten(mesh.bitangent_iter())),
+        MeshAttribute::TextureCoords(channel) if mesh.has_texture_coords(channel) => {
+            Some(flatten(mesh.texture_coords_iter(channel)))
+        }
+        MeshAttribute::Colors(set) if mesh.has_vertex_colors(set) => {
+            Some(mesh.vertex_color_iter(set).flat_map(|c| vec![c.r, c.g, c.b, c.a]).collect())
+        }
+        _ => None
+    }
+}
+
+fn property_id(prop: &MaterialProperty) -> PropertyId {
+    PropertyId {
+        key: prop.key().as_str().to_owned(),
+        semantic: prop.semantic(),
+        index: prop.index()
+    }
+}
+
+// Returns `None` if the values are equal within the tolerance, otherwise the largest difference
+// between their elements if they're numeric values of the same length.
+fn property_error(old: PropertyValue, new: PropertyValue, tolerance: f32) -> Option<Option<f32>> {
+    let error = match (&old, &new) {
+        (&PropertyValue::Float(ref a), &PropertyValue::Float(ref b)) if a.len() == b.len() => {
+            max_error(a, b)
+        }
+        (&PropertyValue::Double(ref a), &PropertyValue::Double(ref b)) if a.len() == b.len() => {
+            a.iter().zip(b).map(|(x, y)| value_error(*x, *y)).fold(0.0, f32::max)
+        }
+        (&PropertyValue::Int(ref a), &PropertyValue::Int(ref b)) if a.len() == b.len() => {
+            // Integers are compared exactly
+            let error = a.iter().zip(b).map(|(x, y)| (*x as i64 - *y as i64).abs()).max().unwrap_or(0);
+            return if error == 0 { None } else { Some(Some(error as f32)) };
+        }
+        _ => return if old == new { None } else { Some(None) }
+    };
+    if error > tolerance { Some(Some(error)) } else { None }
+}
+
+fn key_values(channel: &NodeAnim, keys: KeyType) -> Vec<[f32; 5]> {
+    match keys {
+        KeyType::Position => (0..channel.num_position_keys as usize)
+            .filter_map(|i| channel.get_position_key(i))
+            .map(|k| [k.time as f32, k.value.x, k.value.y, k.value.z, 0.0])
+            .collect(),
+        KeyType::Rotation => (0..channel.num_rotation_keys as usize)
+            .filter_map(|i| channel.get_rotation_key(i))
+            .map(|k| [k.time as f32, k.value.w, k.value.x, k.value.y, k.value.z])
+            .collect(),
+        KeyType::Scaling => (0..channel.num_scaling_keys as usize)
+            .filter_map(|i| channel.get_scaling_key(i))
+            .map(|k| [k.time as f32, k.value.x, k.value.y, k.value.z, 0.0])
+            .collect()
+    }
+}
+
+impl fmt::Display for MeshAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MeshAttribute::Positions => f.write_str("positions"),
+            MeshAttribute::Normals => f.write_str("normals"),
+            MeshAttribute::Tangents => f.write_str("tangents"),
+            MeshAttribute::Bitangents => f.write_str("bitangents"),
+            MeshAttribute::TextureCoords(channel) => write!(f, "texture coordinates {}", channel),
+            MeshAttribute::Colors(set) => write!(f, "colors {}", set)
+        }
+    }
+}
+
+impl fmt::Display for PropertyId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}][{}]", self.key, self.semantic, self.index)
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Difference::NodeAdded { ref path } => write!(f, "node {} added", path),
+            Difference::NodeRemoved { ref path } => write!(f, "node {} removed", path),
+            Difference::NodeRenamed { ref path, ref name } => write!(f, "node {} renamed to {}", path, name),
+            Difference::NodeTransform { ref path, max_error } => {
+                write!(f, "node {} transformation changed (max error {})", path, max_error)
+            }
+            Difference::NodeMeshes { ref path, ref old, ref new } => {
+                write!(f, "node {} meshes changed: {:?} -> {:?}", path, old, new)
+            }
+            Difference::MeshAdded { mesh } => write!(f, "mesh {} added", mesh),
+            Difference::MeshRemoved { mesh } => write!(f, "mesh {} removed", mesh),
+            Difference::MeshCount { mesh, count, old, new } => {
+                let count = match count {
+                    MeshCount::Vertices => "vertices",
+                    MeshCount::Faces => "faces",
+                    MeshCount::Indices => "indices",
+                    MeshCount::Bones => "bones"
+                };
+                write!(f, "mesh {} {} changed: {} -> {}", mesh, count, old, new)
+            }
+            Difference::MeshMaterial { mesh, old, new } => {
+                write!(f, "mesh {} material changed: {} -> {}", mesh, old, new)
+            }
+            Difference::MeshFaces { mesh, changed } => {
+                write!(f, "mesh {} faces changed: {} faces have different indices", mesh, changed)
+            }
+            Difference::MeshAttributeAdded { mesh, attribute } => write!(f, "mesh {} {} added", mesh, attribute),
+            Difference::MeshAttributeRemoved { mesh, attribute } => {
+                write!(f, "mesh {} {} removed", mesh, attribute)
+            }
+            Difference::MeshAttribute { mesh, attribute, max_error } => {
+                write!(f, "mesh {} {} changed (max error {})", mesh, attribute, max_error)
+            }
+            Difference::MaterialAdded { material } => write!(f, "material {} added", material),
+            Difference::MaterialRemoved { material } => write!(f, "material {} removed", material),
+            Difference::MaterialPropertyAdded { material, ref property } => {
+                write!(f, "material {} property {} added", material, property)
+            }
+            Difference::MaterialPropertyRemoved { material, ref property } => {
+                write!(f, "material {} property {} removed", material, property)
+            }
+            Difference::MaterialProperty { material, ref property, max_error: Some(max_error) } => {
+                write!(f, "material {} property {} changed (max error {})", material, property, max_error)
+            }
+            Difference::MaterialProperty { material, ref property, max_error: None } => {
+                write!(f, "material {} property {} changed", material, property)
+            }
+            Difference::AnimationAdded { ref animation } => write!(f, "animation {:?} added", animation),
+            Difference::AnimationRemoved { ref animation } => write!(f, "animation {:?} removed", animation),
+            Difference::AnimationDuration { ref animation, old, new } => {
+                write!(f, "animation {:?} duration changed: {} -> {}", animation, old, new)
+            }
+            Difference::AnimationTicksPerSecond { ref animation, old, new } => {
+                write!(f, "animation {:?} ticks per second changed: {} -> {}", animation, old, new)
+            }
+            Difference::ChannelAdded { ref animation, ref node } => {
+                write!(f, "animation {:?} channel {:?} added", animation, node)
+            }
+            Difference::ChannelRemoved { ref animation, ref node } => {
+                write!(f, "animation {:?} channel {:?} removed", animation, node)
+            }
+            Difference::KeyCount { ref animation, ref node, keys, old, new } => {
+                write!(f, "animation {:?} channel {:?} {} keys changed: {} -> {}", animation, node,
+                       key_type_name(keys), old, new)
+            }
+            Difference::Keys { ref animation, ref node, keys, max_error } => {
+                write!(f, "animation {:?} channel {:?} {} keys changed (max error {})", animation, node,
+                       key_type_name(keys), max_error)
+            }
+        }
+    }
+}
+
+fn key_type_name(keys: KeyType) -> &'static str {
+    match keys {
+        KeyType::Position => "position",
+        KeyType::Rotation => "rotation",
+        KeyType::Scaling => "scaling"
+    }
+}
+
+impl fmt::Display for SceneDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for difference in &self.differences {
+            writeln!(f, "{}", difference)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,492 @@
+use std::fmt::{self, Write};
+
+use math::{Color3D, Matrix4x4, Quaternion, Vector3D};
+
+use super::animation::{Animation, NodeAnim, QuatKey, VectorKey};
+use super::camera::Camera;
+use super::face::Face;
+use super::light::Light;
+use super::material::{Material, MaterialProperty, PropertyValue};
+use super::mesh::{Bone, Mesh, VertexWeight};
+use super::node::Node;
+use super::scene::Scene;
+use super::texture::Texture;
+
+/// Options of `Scene::dump`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DumpOptions {
+    /// Number of decimals floating-point values are rounded to. Default: 4
+    pub precision: usize,
+    /// Include the properties of materials. Default: true
+    pub materials: bool,
+    /// Include the channels of animations. Default: true
+    pub animations: bool,
+    /// Include hashes of vertex, face, weight and key data. Default: true
+    pub hashes: bool
+}
+
+impl Default for DumpOptions {
+    fn default() -> DumpOptions {
+        DumpOptions {
+            precision: 4,
+            materials: true,
+            animations: true,
+            hashes: true
+        }
+    }
+}
+
+impl<'a> Scene<'a> {
+    /// Returns a textual description of the scene, for use in snapshot tests.
+    ///
+    /// The output lists the node hierarchy, meshes, materials, embedded textures, cameras,
+    /// lights and animations. Arrays of vertex data are summarized by their size and a hash, so
+    /// the dump stays readable while still changing whenever the data does. Floating-point
+    /// values, including those hashed, are rounded to `options.precision` decimals, so that
+    /// differences below that precision don't show up. Material properties are sorted by key.
+    ///
+    /// The output doesn't depend on the platform, and hashes don't depend on the Rust version.
+    pub fn dump(&self, options: &DumpOptions) -> String {
+        let mut dumper = Dumper::new(options);
+        dumper.scene(self);
+        dumper.out
+    }
+}
+
+// Writes the description of scene objects to a string, one property per line and indented by
+// nesting level.
+struct Dumper<'o> {
+    out: String,
+    options: &'o DumpOptions,
+    depth: usize
+}
+
+impl<'o> Dumper<'o> {
+    fn new(options: &'o DumpOptions) -> Dumper<'o> {
+        Dumper { out: String::new(), options: options, depth: 0 }
+    }
+
+    fn line(&mut self, args: fmt::Arguments) {
+        for _ in 0..self.depth {
+            self.out.push_str("  ");
+        }
+        let _ = self.out.write_fmt(args);
+        self.out.push('\n');
+    }
+
+    fn nested<F: FnOnce(&mut Dumper<'o>)>(&mut self, f: F) {
+        self.depth += 1;
+        f(self);
+        self.depth -= 1;
+    }
+
+    fn float(&self, value: f64) -> String {
+        let s = format!("{:.*}", self.options.precision, value);
+        // Values rounded to zero are printed without sign
+        if s.starts_with('-') && s[1..].chars().all(|c| c == '0' || c == '.') {
+            s[1..].to_owned()
+        } else {
+            s
+        }
+    }
+
+    fn floats(&self, values: &[f32]) -> String {
+        let values: Vec<String> = values.iter().map(|&x| self.float(x as f64)).collect();
+        format!("({})", values.join(", "))
+    }
+
+    fn vec3(&self, v: Vector3D) -> String {
+        self.floats(&[v.x, v.y, v.z])
+    }
+
+    fn quat(&self, q: Quaternion) -> String {
+        self.floats(&[q.w, q.x, q.y, q.z])
+    }
+
+    fn color3(&self, c: Color3D) -> String {
+        self.floats(&[c.r, c.g, c.b])
+    }
+
+    fn matrix(&self, m: Matrix4x4) -> String {
+        let values = matrix_values(&m);
+        let identity = matrix_values(&Matrix4x4::identity());
+        if values.iter().zip(&identity).all(|(&a, &b)| self.float(a as f64) == self.float(b as f64)) {
+            return "identity".to_owned();
+        }
+        let rows: Vec<String> = values.chunks(4).map(|row| self.floats(row)).collect();
+        format!("[{}]", rows.join(", "))
+    }
+
+    // Hashes values as they're printed, so that the hash only changes if the rounded values do.
+    fn float_hash(&self, values: &[f32]) -> u64 {
+        let mut hash = Fnv1a::new();
+        for &value in values {
+            hash.write(self.float(value as f64).as_bytes());
+            hash.write(b";");
+        }
+        hash.finish()
+    }
+
+    // Formats the size of an array and the hash of its contents.
+    fn summary(&self, count: usize, hash: u64) -> String {
+        if self.options.hashes {
+            format!("{} #{:016x}", count, hash)
+        } else {
+            count.to_string()
+        }
+    }
+
+    fn vec3_summary<I: Iterator<Item = Vector3D>>(&self, iter: I) -> String {
+        let values: Vec<f32> = iter.flat_map(|v| vec![v.x, v.y, v.z]).collect();
+        self.summary(values.len() / 3, self.float_hash(&values))
+    }
+
+    fn scene(&mut self, scene: &Scene) {
+        self.line(format_args!("scene"));
+        self.nested(|d| {
+            let flags: Vec<&str> = [(scene.is_incomplete(), "incomplete"),
+                                    (scene.is_validated(), "validated"),
+                                    (scene.has_validation_warning(), "validation_warning"),
+                                    (scene.is_non_verbose_format(), "non_verbose_format"),
+                                    (scene.is_terrain(), "terrain")]
+                .iter().filter(|x| x.0).map(|x| x.1).collect();
+            if !flags.is_empty() {
+                d.line(format_args!("flags: {}", flags.join(", ")));
+            }
+            if !scene.root_node.is_null() {
+                d.node(&scene.root_node());
+            }
+            for (i, mesh) in scene.mesh_iter().enumerate() {
+                d.line(format_args!("mesh {}", i));
+                d.nested(|d| d.mesh(&mesh));
+            }
+            for (i, material) in scene.material_iter().enumerate() {
+                d.line(format_args!("material {}", i));
+                d.nested(|d| d.material(&material));
+            }
+            for (i, texture) in scene.texture_iter().enumerate() {
+                d.line(format_args!("texture {}", i));
+                d.nested(|d| d.texture(&texture));
+            }
+            for camera in scene.camera_iter() {
+                d.camera(&camera);
+            }
+            for light in scene.light_iter() {
+                d.light(&light);
+            }
+            for (i, animation) in scene.animation_iter().enumerate() {
+                d.line(format_args!("animation {}", i));
+                d.nested(|d| d.animation(&animation));
+            }
+        });
+    }
+
+    fn node(&mut self, node: &Node) {
+        self.line(format_args!("node {:?}", node.name()));
+        self.nested(|d| {
+            let transform = d.matrix(node.transformation());
+            d.line(format_args!("transform: {}", transform));
+            if node.num_meshes() > 0 {
+                d.line(format_args!("meshes: {:?}", node.meshes()));
+            }
+            for child in node.child_iter() {
+                d.node(&child);
+            }
+        });
+    }
+
+    fn mesh(&mut self, mesh: &Mesh) {
+        self.line(format_args!("name: {:?}", mesh.name()));
+        self.line(format_args!("material: {}", mesh.material_index()));
+
+        let types = mesh.primitive_types();
+        let types: Vec<&str> = [(1, "point"), (2, "line"), (4, "triangle"), (8, "polygon")]
+            .iter().filter(|x| types & x.0 != 0).map(|x| x.1).collect();
+        self.line(format_args!("primitives: {}", types.join(", ")));
+
+        let vertices = self.vec3_summary(mesh.vertex_iter());
+        self.line(format_args!("vertices: {}", vertices));
+        if !mesh.normals.is_null() {
+            let normals = self.vec3_summary(mesh.normal_iter());
+            self.line(format_args!("normals: {}", normals));
+        }
+        if !mesh.tangents.is_null() {
+            let tangents = self.vec3_summary(mesh.tangent_iter());
+            let bitangents = self.vec3_summary(mesh.bitangent_iter());
+            self.line(format_args!("tangents: {}", tangents));
+            self.line(format_args!("bitangents: {}", bitangents));
+        }
+        for channel in 0..mesh.num_uv_channels() as usize {
+            let components = mesh.num_uv_components(channel).unwrap_or(0);
+            let coords = self.vec3_summary(mesh.texture_coords_iter(channel));
+            self.line(format_args!("uv {} ({}d): {}", channel, components, coords));
+        }
+        for set in 0..mesh.num_color_sets() as usize {
+            let values: Vec<f32> = mesh.vertex_color_iter(set).flat_map(|c| vec![c.r, c.g, c.b, c.a]).collect();
+            let colors = self.summary(values.len() / 4, self.float_hash(&values));
+            self.line(format_args!("colors {}: {}", set, colors));
+        }
+
+        let mut hash = Fnv1a::new();
+        for face in mesh.face_iter() {
+            hash.write(face_indices(&face).as_bytes());
+            hash.write(b";");
+        }
+        let faces = self.summary(mesh.num_faces() as usize, hash.finish());
+        self.line(format_args!("faces: {}", faces));
+
+        if let Some(aabb) = mesh.aabb() {
+            let (min, max) = (self.vec3(aabb.min), self.vec3(aabb.max));
+            self.line(format_args!("bounds: {} - {}", min, max));
+        }
+        for bone in mesh.bone_iter() {
+            self.bone(&bone);
+        }
+    }
+
+    fn bone(&mut self, bone: &Bone) {
+        self.line(format_args!("bone {:?}", bone.name()));
+        self.nested(|d| {
+            let offset = d.matrix(bone.offset_matrix());
+            d.line(format_args!("offset: {}", offset));
+            let mut hash = Fnv1a::new();
+            for weight in bone.weight_iter() {
+                hash.write(format!("{}:{};", weight.vertex_id, d.float(weight.weight as f64)).as_bytes());
+            }
+            let weights = d.summary(bone.num_weights() as usize, hash.finish());
+            d.line(format_args!("weights: {}", weights));
+        });
+    }
+
+    fn material(&mut self, material: &Material) {
+        let name = material.name().map(|x| x.as_ref().to_owned()).unwrap_or_default();
+        self.line(format_args!("name: {:?}", name));
+        if !self.options.materials {
+            return;
+        }
+        let mut properties: Vec<MaterialProperty> = material.properties_iter().collect();
+        properties.sort_by(|a, b| {
+            (a.key().as_str(), a.semantic(), a.index()).cmp(&(b.key().as_str(), b.semantic(), b.index()))
+        });
+        for prop in properties {
+            self.property(&prop);
+        }
+    }
+
+    fn property(&mut self, prop: &MaterialProperty) {
+        let key = prop.key();
+        let value = self.property_value(prop.value());
+        if prop.semantic() != 0 || prop.index() != 0 {
+            self.line(format_args!("{}[{}][{}]: {}", key, prop.semantic(), prop.index(), value));
+        } else {
+            self.line(format_args!("{}: {}", key, value));
+        }
+    }
+
+    fn property_value(&self, value: PropertyValue) -> String {
+        match value {
+            PropertyValue::Float(values) => self.floats(&values),
+            PropertyValue::Double(values) => {
+                let values: Vec<String> = values.iter().map(|&x| self.float(x)).collect();
+                format!("({})", values.join(", "))
+            }
+            PropertyValue::Int(values) => format!("{:?}", values),
+            PropertyValue::String(s) => format!("{:?}", s),
+            PropertyValue::Buffer(data) => {
+                let mut hash = Fnv1a::new();
+                hash.write(data);
+                format!("{} bytes", self.summary(data.len(), hash.finish()))
+            }
+        }
+    }
+
+    fn texture(&mut self, texture: &Texture) {
+        let hint: String = texture.format_hint.iter()
+            .take_while(|&&c| c != 0)
+            .map(|&c| c as u8 as char)
+            .collect();
+        // Compressed textures have a height of 0 and store their size in bytes as width
+        let (len, size) = if texture.height == 0 {
+            (texture.width as usize, format!("compressed {:?}", hint))
+        } else {
+            (texture.width as usize * texture.height as usize * 4,
+             format!("{}x{}", texture.width, texture.height))
+        };
+        let mut hash = Fnv1a::new();
+        if !texture.data.is_null() {
+            hash.write(unsafe { ::std::slice::from_raw_parts(texture.data as *const u8, len) });
+        }
+        let data = self.summary(len, hash.finish());
+        self.line(format_args!("format: {}", size));
+        self.line(format_args!("data: {} bytes", data));
+    }
+
+    fn camera(&mut self, camera: &Camera) {
+        self.line(format_args!("camera {:?}", camera.name.as_ref()));
+        self.nested(|d| {
+            let (position, look_at, up) = (d.vec3(Vector3D::from_raw(&camera.position)),
+                                           d.vec3(Vector3D::from_raw(&camera.look_at)),
+                                           d.vec3(Vector3D::from_raw(&camera.up)));
+            d.line(format_args!("position: {}", position));
+            d.line(format_args!("look_at: {}", look_at));
+            d.line(format_args!("up: {}", up));
+            let fov = d.float(camera.horizontal_fov as f64);
+            let clip = d.floats(&[camera.clip_plane_near, camera.clip_plane_far]);
+            let aspect = d.float(camera.aspect as f64);
+            d.line(format_args!("horizontal_fov: {}", fov));
+            d.line(format_args!("clip_planes: {}", clip));
+            d.line(format_args!("aspect: {}", aspect));
+        });
+    }
+
+    fn light(&mut self, light: &Light) {
+        self.line(format_args!("light {:?}", light.name.as_ref()));
+        self.nested(|d| {
+            d.line(format_args!("type: {:?}", light.light_type));
+            let (position, direction) = (d.vec3(Vector3D::from_raw(&light.position)),
+                                         d.vec3(Vector3D::from_raw(&light.direction)));
+            d.line(format_args!("position: {}", position));
+            d.line(format_args!("direction: {}", direction));
+            let (diffuse, specular, ambient) = (d.color3(Color3D::from_raw(&light.color_diffuse)),
+                                                d.color3(Color3D::from_raw(&light.color_specular)),
+                                                d.color3(Color3D::from_raw(&light.color_ambient)));
+            d.line(format_args!("diffuse: {}", diffuse));
+            d.line(format_args!("specular: {}", specular));
+            d.line(format_args!("ambient: {}", ambient));
+            let attenuation = d.floats(&[light.attenuation_constant, light.attenuation_linear,
+                                         light.attenuation_quadratic]);
+            let cone = d.floats(&[light.angle_inner_cone, light.angle_outer_cone]);
+            d.line(format_args!("attenuation: {}", attenuation));
+            d.line(format_args!("cone: {}", cone));
+        });
+    }
+
+    fn animation(&mut self, animation: &Animation) {
+        self.line(format_args!("name: {:?}", animation.name.as_ref()));
+        let (duration, ticks) = (self.float(animation.duration), self.float(animation.ticks_per_second));
+        self.line(format_args!("duration: {} ticks", duration));
+        self.line(format_args!("ticks_per_second: {}", ticks));
+        if !self.options.animations {
+            return;
+        }
+        for i in 0..animation.num_channels as usize {
+            if let Some(channel) = animation.get_node_anim(i) {
+                self.node_anim(&channel);
+            }
+        }
+    }
+
+    fn node_anim(&mut self, channel: &NodeAnim) {
+        self.line(format_args!("channel {:?}", channel.node_name.as_ref()));
+        self.nested(|d| {
+            let positions: Vec<f32> = (0..channel.num_position_keys as usize)
+                .filter_map(|i| channel.get_position_key(i))
+                .flat_map(|k| vec![k.time as f32, k.value.x, k.value.y, k.value.z])
+                .collect();
+            let rotations: Vec<f32> = (0..channel.num_rotation_keys as usize)
+                .filter_map(|i| channel.get_rotation_key(i))
+                .flat_map(|k| vec![k.time as f32, k.value.w, k.value.x, k.value.y, k.value.z])
+                .collect();
+            let scalings: Vec<f32> = (0..channel.num_scaling_keys as usize)
+                .filter_map(|i| channel.get_scaling_key(i))
+                .flat_map(|k| vec![k.time as f32, k.value.x, k.value.y, k.value.z])
+                .collect();
+            let positions = d.summary(channel.num_position_keys as usize, d.float_hash(&positions));
+            let rotations = d.summary(channel.num_rotation_keys as usize, d.float_hash(&rotations));
+            let scalings = d.summary(channel.num_scaling_keys as usize, d.float_hash(&scalings));
+            d.line(format_args!("position_keys: {}", positions));
+            d.line(format_args!("rotation_keys: {}", rotations));
+            d.line(format_args!("scaling_keys: {}", scalings));
+            d.line(format_args!("pre_state: {:?}", channel.pre_state));
+            d.line(format_args!("post_state: {:?}", channel.post_state));
+        });
+    }
+}
+
+fn matrix_values(m: &Matrix4x4) -> [f32; 16] {
+    [m.a1, m.a2, m.a3, m.a4, m.b1, m.b2, m.b3, m.b4,
+     m.c1, m.c2, m.c3, m.c4, m.d1, m.d2, m.d3, m.d4]
+}
+
+fn face_indices(face: &Face) -> String {
+    let indices: Vec<String> = (0..face.num_indices as isize).map(|i| face[i].to_string()).collect();
+    indices.join(", ")
+}
+
+// 64-bit FNV-1a, used instead of `DefaultHasher` whose output isn't guaranteed to be stable
+// between Rust versions.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Fnv1a {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// Implements `Debug` by dumping the object with the default options.
+macro_rules! impl_debug_with_dump {
+    ($($name:ident => |$dumper:ident, $value:ident| $body:expr;)*) => ($(
+        impl<'a> fmt::Debug for $name<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let options = DumpOptions::default();
+                let mut $dumper = Dumper::new(&options);
+                let $value = self;
+                $body;
+                f.write_str($dumper.out.trim_end())
+            }
+        }
+    )*)
+}
+
+impl_debug_with_dump! {
+    Scene => |d, scene| d.scene(scene);
+    Node => |d, node| d.node(node);
+    Mesh => |d, mesh| { d.line(format_args!("mesh")); d.nested(|d| d.mesh(mesh)) };
+    Bone => |d, bone| d.bone(bone);
+    Material => |d, material| { d.line(format_args!("material")); d.nested(|d| d.material(material)) };
+    MaterialProperty => |d, prop| d.property(prop);
+    Texture => |d, texture| { d.line(format_args!("texture")); d.nested(|d| d.texture(texture)) };
+    Camera => |d, camera| d.camera(camera);
+    Light => |d, light| d.light(light);
+    Animation => |d, animation| { d.line(format_args!("animation")); d.nested(|d| d.animation(animation)) };
+    NodeAnim => |d, channel| d.node_anim(channel);
+}
+
+impl<'a> fmt::Debug for Face<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "face ({})", face_indices(self))
+    }
+}
+
+impl<'a> fmt::Debug for VertexWeight<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = DumpOptions::default();
+        write!(f, "weight {}: {}", self.vertex_id, Dumper::new(&options).float(self.weight as f64))
+    }
+}
+
+impl<'a> fmt::Debug for VectorKey<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = DumpOptions::default();
+        let d = Dumper::new(&options);
+        write!(f, "key {}: {}", d.float(self.time), d.vec3(Vector3D::from_raw(&self.value)))
+    }
+}
+
+impl<'a> fmt::Debug for QuatKey<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let options = DumpOptions::default();
+        let d = Dumper::new(&options);
+        write!(f, "key {}: {}", d.float(self.time), d.quat(Quaternion::from_raw(&self.value)))
+    }
+}
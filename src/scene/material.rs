@@ -1,11 +1,16 @@
 use ffi::{
     AiVector3D, AiColor4D, AiMaterial, AiMaterialProperty, AiPropertyTypeInfo, AiString, AiShadingMode,
     AiTextureOp, AiTextureType, AiTextureMapMode, AiTextureMapping, AiTextureFlags, AiBlendMode, AiUVTransform,
-    aiGetMaterialColor, aiGetMaterialString, aiGetMaterialIntegerArray, aiGetMaterialFloatArray,
+    AiReturn, AiVector2D, aiGetMaterialString, aiGetMaterialIntegerArray, aiGetMaterialFloatArray,
+    aiGetMaterialTextureCount,
 };
-use std::ptr::{null_mut};
+use std::os::raw::c_char;
+use std::slice;
 use std::marker::PhantomData;
 
+use math::{Color3D, Color4D, Vector2D, Vector3D};
+use super::material_key::MaterialPropertyKey;
+
 define_type_and_iterator_indirect! {
     /// Material type (not yet implemented)
     struct Material(&AiMaterial)
@@ -27,6 +32,62 @@ pub struct TextureValue<'a, T: 'a, U> {
     phantom: PhantomData<&'a T>
 }
 
+// Raw values of `aiPropertyTypeInfo`
+const PTI_FLOAT: u32 = 0x1;
+const PTI_DOUBLE: u32 = 0x2;
+const PTI_STRING: u32 = 0x3;
+const PTI_INTEGER: u32 = 0x4;
+
+/// Decoded value of a material property.
+///
+/// See `MaterialProperty::value`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue<'a> {
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    Int(Vec<i32>),
+    String(String),
+    /// Raw data of properties with no specific type.
+    Buffer(&'a [u8])
+}
+
+/// Transformation applied to the UV coordinates of a texture.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UVTransform {
+    pub translation: Vector2D,
+    pub scaling: Vector2D,
+    /// Counter-clockwise rotation around the center of the texture, in radians.
+    pub rotation: f32
+}
+
+/// All properties of a single texture of a material.
+///
+/// See `Material::textures`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextureSlot {
+    pub texture_type: AiTextureType,
+    /// Raw `aiTextureType` of the texture. This differs from `texture_type` for texture types
+    /// newer than `AiTextureType`, which are reported as `Unknown`.
+    pub semantic: u32,
+    /// Index of the texture within its type.
+    pub index: u32,
+    /// Path of the texture, or `*<n>` for the n-th embedded texture.
+    pub path: String,
+    /// Index of the UV channel used by the texture.
+    pub uv_index: u32,
+    /// Strength of the texture.
+    pub blend: f32,
+    /// How the texture is combined with the previous texture of its type.
+    pub op: Option<AiTextureOp>,
+    pub mapping: AiTextureMapping,
+    pub map_mode_u: AiTextureMapMode,
+    pub map_mode_v: AiTextureMapMode,
+    /// Axis of non-UV mappings.
+    pub axis: Option<Vector3D>,
+    pub uv_transform: Option<UVTransform>,
+    pub flags: AiTextureFlags
+}
+
 impl<'a> Material<'a> {
     pub fn num_properties(&self) -> u32 { self.num_properties }
 
@@ -46,437 +107,255 @@ impl<'a> Material<'a> {
     }
 
     pub fn global_background(&self) -> Option<AiString> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::GlobalBackground(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::GlobalBackground, |prop| prop.string_value())
     }
 
     pub fn diffuse_color(&self) -> Option<AiColor4D> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::DiffuseColor(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::DiffuseColor, |prop| prop.color_value())
     }
 
     pub fn ambient_color(&self) -> Option<AiColor4D> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::AmbientColor(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::AmbientColor, |prop| prop.color_value())
     }
 
     pub fn emissive_color(&self) -> Option<AiColor4D> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::EmissiveColor(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::EmissiveColor, |prop| prop.color_value())
     }
 
     pub fn specular_color(&self) -> Option<AiColor4D> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::SpecularColor(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::SpecularColor, |prop| prop.color_value())
     }
 
     pub fn transparent_color(&self) -> Option<AiColor4D> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TransparentColor(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::TransparentColor, |prop| prop.color_value())
     }
 
     pub fn reflective_color(&self) -> Option<AiColor4D> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::ReflectiveColor(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::ReflectiveColor, |prop| prop.color_value())
     }
 
     pub fn name(&self) -> Option<AiString> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::Name(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::Name, |prop| prop.string_value())
     }
 
     pub fn shading_model(&self) -> Option<AiShadingMode> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::ShadingModel(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::ShadingModel, |prop| prop.shading_mode_value())
     }
 
     pub fn two_sided(&self) -> Option<bool> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TwoSided(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::TwoSided, |prop| prop.bool_value())
     }
 
     pub fn wireframe(&self) -> Option<bool> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::Wireframe(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::Wireframe, |prop| prop.bool_value())
     }
 
     pub fn blend_mode(&self) -> Option<AiBlendMode> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::BlendMode(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::BlendMode, |prop| prop.blend_mode_value())
     }
 
     pub fn opacity(&self) -> Option<f32> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::Opacity(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::Opacity, |prop| prop.float_value())
     }
 
     pub fn bump_scaling(&self) -> Option<f32> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::BumpScaling(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::BumpScaling, |prop| prop.float_value())
     }
 
     pub fn shininess(&self) -> Option<f32> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::Shininess(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::Shininess, |prop| prop.float_value())
     }
 
     pub fn reflectivity(&self) -> Option<f32> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::Reflectivity(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::Reflectivity, |prop| prop.float_value())
     }
 
     pub fn shininess_strength(&self) -> Option<f32> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::ShininessStrength(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::ShininessStrength, |prop| prop.float_value())
     }
 
     pub fn index_of_refraction(&self) -> Option<f32> {
-        self
-          .properties_iter()
-          .flat_map(|prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::IndexOfRefraction(value)) => Some(value),
-                  _ => None,
-              }
-          })
-          .nth(0)
+        self.find_value(MaterialPropertyKey::IndexOfRefraction, |prop| prop.float_value())
     }
 
     pub fn texture_path(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiString>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TexturePath(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TexturePath, move |prop| prop.string_value())
     }
 
     pub fn texture_uvw_source(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, i32>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureUvwSource(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureUvwSource, move |prop| prop.int_value())
     }
 
     pub fn texture_op(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiTextureOp>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureOp(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureOp, move |prop| prop.texture_op_value())
     }
 
     pub fn texture_mapping(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiTextureMapping>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureMapping(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureMapping, move |prop| prop.texture_mapping_value())
     }
 
     pub fn texture_blend(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, f32>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureBlend(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureBlend, move |prop| prop.float_value())
     }
 
     pub fn texture_mapping_mode_u(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiTextureMapMode>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureMappingModeU(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureMappingModeU, move |prop| prop.texture_map_mode_value())
     }
 
     pub fn texture_mapping_mode_v(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiTextureMapMode>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureMappingModeV(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureMappingModeV, move |prop| prop.texture_map_mode_value())
     }
 
     pub fn texture_map_axis(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiVector3D>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureMapAxis(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureMapAxis, move |prop| prop.vector_value())
     }
 
     pub fn texture_uv_transform(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiUVTransform>> {
-        self
-          .properties_iter()
-          .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureUvTransform(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
-          })
+        self.texture_values(MaterialPropertyKey::TextureUvTransform, move |prop| prop.uv_transform_value())
     }
 
     pub fn texture_flags(&'a self) -> impl Iterator<Item = TextureValue<'a, Material, AiTextureFlags>> {
+        self.texture_values(MaterialPropertyKey::TextureFlags, move |prop| prop.texture_flags_value())
+    }
+
+    // Returns the first value of the given key that could be decoded
+    fn find_value<T, F>(&self, key: MaterialPropertyKey, decode: F) -> Option<T>
+        where F: Fn(&MaterialProperty) -> Option<T>
+    {
+        self.properties_iter()
+          .filter(|prop| prop.key() == key)
+          .filter_map(|prop| decode(&prop))
+          .nth(0)
+    }
+
+    fn texture_values<T, F>(&'a self, key: MaterialPropertyKey, decode: F)
+        -> impl Iterator<Item = TextureValue<'a, Material<'a>, T>>
+        where F: Fn(&MaterialProperty) -> Option<T> + 'a
+    {
         self
           .properties_iter()
+          .filter(move |prop| prop.key() == key)
           .flat_map(move |prop| {
-              match prop.key(self) {
-                  Some(MaterialPropertyKey::TextureFlags(texture_type, index, value)) => {
-                      Some(TextureValue {
-                          texture_type,
-                          index,
-                          value,
-                          phantom: PhantomData,
-                      })
-                  },
-                  _ => None,
-              }
+              decode(&prop).map(|value| {
+                  TextureValue {
+                      texture_type: texture_type_from_raw(prop.semantic),
+                      index: prop.index,
+                      value,
+                      phantom: PhantomData,
+                  }
+              })
           })
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum MaterialPropertyKey {
-    GlobalBackground(AiString),
-    DiffuseColor(AiColor4D),
-    AmbientColor(AiColor4D),
-    EmissiveColor(AiColor4D),
-    SpecularColor(AiColor4D),
-    TransparentColor(AiColor4D),
-    ReflectiveColor(AiColor4D),
-    Name(AiString),
-    ShadingModel(AiShadingMode),
-    TwoSided(bool),
-    Wireframe(bool),
-    BlendMode(AiBlendMode),
-    Opacity(f32),
-    BumpScaling(f32),
-    Shininess(f32),
-    Reflectivity(f32),
-    ShininessStrength(f32),
-    IndexOfRefraction(f32),
-    TexturePath(AiTextureType, u32, AiString),
-    TextureUvwSource(AiTextureType, u32, i32),
-    TextureOp(AiTextureType, u32, AiTextureOp),
-    TextureMapping(AiTextureType, u32, AiTextureMapping),
-    TextureBlend(AiTextureType, u32, f32),
-    TextureMappingModeU(AiTextureType, u32, AiTextureMapMode),
-    TextureMappingModeV(AiTextureType, u32, AiTextureMapMode),
-    TextureMapAxis(AiTextureType, u32, AiVector3D),
-    TextureUvTransform(AiTextureType, u32, AiUVTransform),
-    TextureFlags(AiTextureType, u32, AiTextureFlags),
-}
+impl<'a> Material<'a> {
+    /// Returns the textures of the given type, in order of their index.
+    ///
+    /// Properties missing from the material are given Assimp's defaults, as with
+    /// `aiGetMaterialTexture`.
+    pub fn textures(&self, texture_type: AiTextureType) -> Vec<TextureSlot> {
+        let count = unsafe { aiGetMaterialTextureCount(self.to_raw(), texture_type) };
+        (0..count).filter_map(|index| self.texture_slot(texture_type as u32, index)).collect()
+    }
+
+    /// Returns the texture with the given raw `aiTextureType` and index.
+    pub(crate) fn texture_slot(&self, semantic: u32, index: u32) -> Option<TextureSlot> {
+        let path = self.get_string_at(b"$tex.file\0", semantic, index)?;
+        let int = |key: &[u8]| self.get_int_at(key, semantic, index);
+
+        let mut axis = [0.0; 3];
+        let axis = match self.get_floats(b"$tex.mapaxis\0", semantic, index, &mut axis) {
+            Some(3) => Some(Vector3D::new(axis[0], axis[1], axis[2])),
+            _ => None
+        };
+        let mut transform = [0.0; 5];
+        let uv_transform = match self.get_floats(b"$tex.uvtrafo\0", semantic, index, &mut transform) {
+            Some(5) => Some(UVTransform {
+                translation: Vector2D::new(transform[0], transform[1]),
+                scaling: Vector2D::new(transform[2], transform[3]),
+                rotation: transform[4]
+            }),
+            _ => None
+        };
+        let mut blend = [1.0];
+        self.get_floats(b"$tex.blend\0", semantic, index, &mut blend);
+
+        Some(TextureSlot {
+            texture_type: texture_type_from_raw(semantic),
+            semantic: semantic,
+            index: index,
+            path: path,
+            uv_index: int(b"$tex.uvwsrc\0").unwrap_or(0) as u32,
+            blend: blend[0],
+            op: int(b"$tex.op\0").and_then(texture_op_from_raw),
+            mapping: int(b"$tex.mapping\0").and_then(texture_mapping_from_raw)
+                .unwrap_or(AiTextureMapping::UV),
+            map_mode_u: int(b"$tex.mapmodeu\0").and_then(texture_map_mode_from_raw)
+                .unwrap_or(AiTextureMapMode::Wrap),
+            map_mode_v: int(b"$tex.mapmodev\0").and_then(texture_map_mode_from_raw)
+                .unwrap_or(AiTextureMapMode::Wrap),
+            axis: axis,
+            uv_transform: uv_transform,
+            flags: int(b"$tex.flags\0").and_then(|x| AiTextureFlags::from_bits(x as u32))
+                .unwrap_or(AiTextureFlags::empty())
+        })
+    }
+
+    // Keys must be nul-terminated.
+    pub(crate) fn get_floats(&self, key: &[u8], semantic: u32, index: u32, out: &mut [f32]) -> Option<usize> {
+        let mut len = out.len() as u32;
+        let result = unsafe {
+            aiGetMaterialFloatArray(self.to_raw(), key.as_ptr() as *const c_char, semantic, index,
+                                    out.as_mut_ptr(), &mut len)
+        };
+        if result == AiReturn::Success { Some(len as usize) } else { None }
+    }
+
+    pub(crate) fn get_float(&self, key: &[u8]) -> Option<f32> {
+        let mut out = [0.0];
+        self.get_floats(key, 0, 0, &mut out).map(|_| out[0])
+    }
+
+    pub(crate) fn get_color3(&self, key: &[u8]) -> Option<Color3D> {
+        let mut out = [0.0; 4];
+        match self.get_floats(key, 0, 0, &mut out) {
+            Some(n) if n >= 3 => Some(Color3D::new(out[0], out[1], out[2])),
+            _ => None
+        }
+    }
+
+    pub(crate) fn get_color4(&self, key: &[u8]) -> Option<Color4D> {
+        let mut out = [0.0, 0.0, 0.0, 1.0];
+        match self.get_floats(key, 0, 0, &mut out) {
+            Some(n) if n >= 3 => Some(Color4D::new(out[0], out[1], out[2], out[3])),
+            _ => None
+        }
+    }
+
+    pub(crate) fn get_int_at(&self, key: &[u8], semantic: u32, index: u32) -> Option<i32> {
+        let mut out = 0;
+        let mut len = 1;
+        let result = unsafe {
+            aiGetMaterialIntegerArray(self.to_raw(), key.as_ptr() as *const c_char, semantic, index,
+                                      &mut out, &mut len)
+        };
+        if result == AiReturn::Success { Some(out) } else { None }
+    }
 
-impl PartialEq for MaterialPropertyKey {
-    fn eq(&self, other: &MaterialPropertyKey) -> bool {
-        std::mem::discriminant(self) == std::mem::discriminant(other)
+    pub(crate) fn get_int(&self, key: &[u8]) -> Option<i32> {
+        self.get_int_at(key, 0, 0)
+    }
+
+    pub(crate) fn get_string_at(&self, key: &[u8], semantic: u32, index: u32) -> Option<String> {
+        let mut out = AiString::default();
+        let result = unsafe {
+            aiGetMaterialString(self.to_raw(), key.as_ptr() as *const c_char, semantic, index, &mut out)
+        };
+        if result == AiReturn::Success { Some(out.as_ref().to_owned()) } else { None }
+    }
+
+    pub(crate) fn get_string(&self, key: &[u8]) -> Option<String> {
+        self.get_string_at(key, 0, 0)
     }
 }
 
@@ -499,39 +378,79 @@ fn texture_type_from_raw(item: u32) -> AiTextureType {
     }
 }
 
+fn texture_op_from_raw(item: i32) -> Option<AiTextureOp> {
+    match item {
+        0x0 => Some(AiTextureOp::Multiply),
+        0x1 => Some(AiTextureOp::Add),
+        0x2 => Some(AiTextureOp::Subtract),
+        0x3 => Some(AiTextureOp::Divide),
+        0x4 => Some(AiTextureOp::SmoothAdd),
+        0x5 => Some(AiTextureOp::SignedAdd),
+        _ => None
+    }
+}
+
+fn texture_mapping_from_raw(item: i32) -> Option<AiTextureMapping> {
+    match item {
+        0x0 => Some(AiTextureMapping::UV),
+        0x1 => Some(AiTextureMapping::Sphere),
+        0x2 => Some(AiTextureMapping::Cylinder),
+        0x3 => Some(AiTextureMapping::Box),
+        0x4 => Some(AiTextureMapping::Plane),
+        0x5 => Some(AiTextureMapping::Other),
+        _ => None,
+    }
+}
+
+fn texture_map_mode_from_raw(item: i32) -> Option<AiTextureMapMode> {
+    match item {
+        0x0 => Some(AiTextureMapMode::Wrap),
+        0x1 => Some(AiTextureMapMode::Clamp),
+        0x2 => Some(AiTextureMapMode::Mirror),
+        0x3 => Some(AiTextureMapMode::Decal),
+        _ => None,
+    }
+}
+
 impl<'a> MaterialProperty<'a> {
     /// Returns the key of the property.
-    pub fn key(&self, mat: &Material) -> Option<MaterialPropertyKey> {
-        match self.key.as_ref() {
-            "?bg.global" => self.string_value(mat).map(MaterialPropertyKey::GlobalBackground),
-            "$clr.diffuse" => self.color_value(mat).map(MaterialPropertyKey::DiffuseColor),
-            "$clr.specular" => self.color_value(mat).map(MaterialPropertyKey::SpecularColor),
-            "$clr.ambient" => self.color_value(mat).map(MaterialPropertyKey::AmbientColor),
-            "$clr.emissive" => self.color_value(mat).map(MaterialPropertyKey::EmissiveColor),
-            "$clr.transparent" => self.color_value(mat).map(MaterialPropertyKey::TransparentColor),
-            "$clr.reflective" => self.color_value(mat).map(MaterialPropertyKey::ReflectiveColor),
-            "?mat.name" => self.string_value(mat).map(MaterialPropertyKey::Name),
-            "$mat.shadingm" => self.shading_mode_value(mat).map(MaterialPropertyKey::ShadingModel),
-            "$mat.twosided" => self.bool_value(mat).map(MaterialPropertyKey::TwoSided),
-            "$mat.wireframe" => self.bool_value(mat).map(MaterialPropertyKey::Wireframe),
-            "$mat.blend" => self.blend_mode_value(mat).map(MaterialPropertyKey::BlendMode),
-            "$mat.opacity" => self.float_value(mat).map(MaterialPropertyKey::Opacity),
-            "$mat.bumpscaling" => self.float_value(mat).map(MaterialPropertyKey::BumpScaling),
-            "$mat.shininess" => self.float_value(mat).map(MaterialPropertyKey::Shininess),
-            "$mat.reflectivity" => self.float_value(mat).map(MaterialPropertyKey::Reflectivity),
-            "$mat.shinpercent" => self.float_value(mat).map(MaterialPropertyKey::ShininessStrength),
-            "$mat.refracti" => self.float_value(mat).map(MaterialPropertyKey::IndexOfRefraction),
-            "$tex.file" => self.string_value(mat).map(|v| MaterialPropertyKey::TexturePath(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.uvwsrc" => self.int_value(mat).map(|v| MaterialPropertyKey::TextureUvwSource(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.op" => self.texture_op_value(mat).map(|v| MaterialPropertyKey::TextureOp(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.mapping" => self.texture_mapping_value(mat).map(|v| MaterialPropertyKey::TextureMapping(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.blend" => self.float_value(mat).map(|v| MaterialPropertyKey::TextureBlend(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.mapmodeu" => self.texture_map_mode_value(mat).map(|v| MaterialPropertyKey::TextureMappingModeU(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.mapmodev" => self.texture_map_mode_value(mat).map(|v| MaterialPropertyKey::TextureMappingModeV(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.mapaxis" => self.vector_value(mat).map(|v| MaterialPropertyKey::TextureMapAxis(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.uvtrafo" => self.uv_transform_value(mat).map(|v| MaterialPropertyKey::TextureUvTransform(texture_type_from_raw(self.semantic), self.index, v)),
-            "$tex.flags" => self.texture_flags_value(mat).map(|v| MaterialPropertyKey::TextureFlags(texture_type_from_raw(self.semantic), self.index, v)),
-            _ => None,
+    pub fn key(&self) -> MaterialPropertyKey {
+        MaterialPropertyKey::from(self.key.as_ref())
+    }
+
+    /// Returns the semantic of the property, the raw `aiTextureType` for texture properties.
+    pub fn semantic(&self) -> u32 {
+        self.semantic
+    }
+
+    /// Decodes the raw data of the property according to its type.
+    pub fn value(&self) -> PropertyValue<'a> {
+        let data: &'a [u8] = if self.data.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.data as *const u8, self.data_length as usize) }
+        };
+
+        // Read the type as an integer, an unknown value in `AiPropertyTypeInfo` would be undefined
+        // behaviour.
+        let property_type = unsafe { *(&self.property_type as *const AiPropertyTypeInfo as *const u32) };
+        match property_type {
+            PTI_FLOAT => PropertyValue::Float(data.chunks_exact(4).map(|x| {
+                f32::from_bits(u32::from_ne_bytes([x[0], x[1], x[2], x[3]]))
+            }).collect()),
+            PTI_DOUBLE => PropertyValue::Double(data.chunks_exact(8).map(|x| {
+                f64::from_bits(u64::from_ne_bytes([x[0], x[1], x[2], x[3], x[4], x[5], x[6], x[7]]))
+            }).collect()),
+            PTI_INTEGER => PropertyValue::Int(data.chunks_exact(4).map(|x| {
+                i32::from_ne_bytes([x[0], x[1], x[2], x[3]])
+            }).collect()),
+            // Strings are stored as a 32-bit length, followed by the nul-terminated string
+            PTI_STRING if data.len() >= 4 => {
+                let len = u32::from_ne_bytes([data[0], data[1], data[2], data[3]]) as usize;
+                let bytes = &data[4..];
+                PropertyValue::String(String::from_utf8_lossy(&bytes[..len.min(bytes.len())]).into_owned())
+            }
+            _ => PropertyValue::Buffer(data)
         }
     }
 
@@ -543,8 +462,8 @@ impl<'a> MaterialProperty<'a> {
         self.property_type
     }
 
-    pub fn shading_mode_value(&self, mat: &Material) -> Option<AiShadingMode> {
-        if let Some(value) = self.int_value(mat) {
+    pub fn shading_mode_value(&self) -> Option<AiShadingMode> {
+        if let Some(value) = self.int_value() {
             match value {
                 0x1 => Some(AiShadingMode::Flat),
                 0x2 => Some(AiShadingMode::Gouraud),
@@ -563,86 +482,48 @@ impl<'a> MaterialProperty<'a> {
         }
     }
 
-    pub fn blend_mode_value(&self, mat: &Material) -> Option<AiBlendMode> {
-        if let Some(value) = self.int_value(mat) {
+    pub fn blend_mode_value(&self) -> Option<AiBlendMode> {
+        if let Some(value) = self.int_value() {
             match value {
+                0x0 => Some(AiBlendMode::Default),
                 0x1 => Some(AiBlendMode::Additive),
-                _ => Some(AiBlendMode::Default),
+                _ => None,
             }
         } else {
             None
         }
     }
 
-    pub fn texture_op_value(&self, mat: &Material) -> Option<AiTextureOp> {
-        if let Some(value) = self.int_value(mat) {
-            match value {
-                0x0 => Some(AiTextureOp::Multiply),
-                0x1 => Some(AiTextureOp::Add),
-                0x2 => Some(AiTextureOp::Subtract),
-                0x3 => Some(AiTextureOp::Divide),
-                0x4 => Some(AiTextureOp::SmoothAdd),
-                0x5 => Some(AiTextureOp::SignedAdd),
-                _ => None
-            }
-        } else {
-            None
-        }
+    pub fn texture_op_value(&self) -> Option<AiTextureOp> {
+        self.int_value().and_then(texture_op_from_raw)
     }
 
-    pub fn texture_mapping_value(&self, mat: &Material) -> Option<AiTextureMapping> {
-        if let Some(value) = self.int_value(mat) {
-            match value {
-                0x0 => Some(AiTextureMapping::UV),
-                0x1 => Some(AiTextureMapping::Sphere),
-                0x2 => Some(AiTextureMapping::Cylinder),
-                0x3 => Some(AiTextureMapping::Box),
-                0x4 => Some(AiTextureMapping::Plane),
-                0x5 => Some(AiTextureMapping::Other),
-                _ => None,
-            }
-        } else {
-            None
-        }
+    pub fn texture_mapping_value(&self) -> Option<AiTextureMapping> {
+        self.int_value().and_then(texture_mapping_from_raw)
     }
 
-    pub fn texture_map_mode_value(&self, mat: &Material) -> Option<AiTextureMapMode> {
-        if let Some(value) = self.int_value(mat) {
-            match value {
-                0x0 => Some(AiTextureMapMode::Wrap),
-                0x1 => Some(AiTextureMapMode::Clamp),
-                0x2 => Some(AiTextureMapMode::Mirror),
-                0x3 => Some(AiTextureMapMode::Decal),
-                _ => None,
-            }
-        } else {
-            None
-        }
+    pub fn texture_map_mode_value(&self) -> Option<AiTextureMapMode> {
+        self.int_value().and_then(texture_map_mode_from_raw)
     }
 
-    pub fn texture_flags_value(&self, mat: &Material) -> Option<AiTextureFlags> {
-        if let Some(value) = self.int_value(mat) {
+    pub fn texture_flags_value(&self) -> Option<AiTextureFlags> {
+        if let Some(value) = self.int_value() {
             AiTextureFlags::from_bits(value as u32)
         } else {
             None
         }
     }
 
-    pub fn uv_transform_value(&self, mat: &Material) -> Option<AiUVTransform> {
-        if self.property_type == AiPropertyTypeInfo::Float {
-            unsafe {
-                let mut out: AiUVTransform = std::mem::zeroed();
-                let mut length: u32 = std::mem::size_of_val(&out) as u32;
-                aiGetMaterialFloatArray(mat.0, std::mem::transmute(self.key.data.as_ptr()), self.semantic, self.index, std::mem::transmute(&mut out), &mut length);
-                Some(out)
-            }
-        } else {
-            None
-        }
+    pub fn uv_transform_value(&self) -> Option<AiUVTransform> {
+        self.float_values(5).map(|x| AiUVTransform {
+            translation: AiVector2D { x: x[0], y: x[1] },
+            scaling: AiVector2D { x: x[2], y: x[3] },
+            rotation: x[4]
+        })
     }
 
-    pub fn bool_value(&self, mat: &Material) -> Option<bool> {
-        self.int_value(mat).map(|i| {
+    pub fn bool_value(&self) -> Option<bool> {
+        self.int_value().map(|i| {
             match i {
                 0 => false,
                 _ => true,
@@ -650,55 +531,41 @@ impl<'a> MaterialProperty<'a> {
         })
     }
 
-    pub fn float_value(&self, mat: &Material) -> Option<f32> {
-        if self.property_type == AiPropertyTypeInfo::Float {
-            let mut out = 0.0f32;
-            let mut length: u32 = 1;
-            unsafe { aiGetMaterialFloatArray(mat.0, std::mem::transmute(self.key.data.as_ptr()), self.semantic, self.index, &mut out, &mut length); };
-            Some(out)
-        } else {
-            None
-        }
+    pub fn float_value(&self) -> Option<f32> {
+        self.float_values(1).map(|x| x[0])
     }
 
-    pub fn vector_value(&self, mat: &Material) -> Option<AiVector3D> {
-        if self.property_type == AiPropertyTypeInfo::Float {
-            let mut out = AiVector3D { x: 0.0, y: 0.0, z: 0.0 };
-            let mut length: u32 = std::mem::size_of::<AiVector3D>() as u32;
-            unsafe { aiGetMaterialFloatArray(mat.0, std::mem::transmute(self.key.data.as_ptr()), self.semantic, self.index, std::mem::transmute(&mut out), &mut length); };
-            Some(out)
-        } else {
-            None
-        }
+    pub fn vector_value(&self) -> Option<AiVector3D> {
+        self.float_values(3).map(|x| AiVector3D { x: x[0], y: x[1], z: x[2] })
     }
 
-    pub fn int_value(&self, mat: &Material) -> Option<i32> {
-        if self.property_type == AiPropertyTypeInfo::Integer {
-            let mut out: i32 = 0;
-            unsafe { aiGetMaterialIntegerArray(mat.0, std::mem::transmute(self.key.data.as_ptr()), self.semantic, self.index, &mut out, null_mut()); };
-            Some(out)
-        } else {
-            None
+    pub fn int_value(&self) -> Option<i32> {
+        match self.value() {
+            PropertyValue::Int(values) => values.first().cloned(),
+            _ => None
         }
     }
 
-    fn color_value(&self, mat: &Material) -> Option<AiColor4D> {
-        if self.property_type == AiPropertyTypeInfo::Float {
-            let mut out = AiColor4D { r: 0.0, g: 0.0, b:0.0, a: 0.0 };
-            unsafe { aiGetMaterialColor(mat.0, std::mem::transmute(self.key.data.as_ptr()), self.semantic, self.index, &mut out); };
-            Some(out)
-        } else {
-            None
-        }
+    // Colors may be stored without alpha, which then defaults to 1 as in `aiGetMaterialColor`
+    fn color_value(&self) -> Option<AiColor4D> {
+        self.float_values(3).map(|x| {
+            AiColor4D { r: x[0], g: x[1], b: x[2], a: x.get(3).cloned().unwrap_or(1.0) }
+        })
     }
 
-    fn string_value(&self, mat: &Material) -> Option<AiString> {
-        if self.property_type == AiPropertyTypeInfo::String {
-            let mut out = AiString::default();
-            unsafe { aiGetMaterialString(mat.0, std::mem::transmute(self.key.data.as_ptr()), self.semantic, self.index, &mut out); };
-            Some(out)
-        } else {
-            None
+    fn string_value(&self) -> Option<AiString> {
+        match self.value() {
+            PropertyValue::String(value) => Some(AiString::from(&*value)),
+            _ => None
         }
     }
+
+    // Returns the float values of the property if it has at least `len` of them
+    fn float_values(&self, len: usize) -> Option<Vec<f32>> {
+        match self.value() {
+            PropertyValue::Float(values) => Some(values),
+            PropertyValue::Double(values) => Some(values.iter().map(|&x| x as f32).collect()),
+            _ => None
+        }.filter(|x| x.len() >= len)
+    }
 }
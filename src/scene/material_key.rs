@@ -0,0 +1,131 @@
+use std::fmt;
+
+// Generates `MaterialPropertyKey` and its conversions from a single table, so the two
+// directions can't get out of sync.
+macro_rules! material_property_keys {
+    ($($(#[$attr:meta])* $variant:ident => $key:expr,)*) => (
+        /// Keys of material properties.
+        ///
+        /// These correspond to the `AI_MATKEY_*` constants in Assimp's `material.h` and
+        /// `pbrmaterial.h`, including those only written by newer versions of Assimp than the
+        /// one bundled with assimp-sys. Texture properties share a key for all textures, the
+        /// texture is identified by the semantic and index of the property.
+        ///
+        /// Keys that aren't listed here are preserved as `Raw`.
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub enum MaterialPropertyKey {
+            $($(#[$attr])* $variant,)*
+            /// A key that isn't one of Assimp's standard keys.
+            Raw(String)
+        }
+
+        impl MaterialPropertyKey {
+            /// Returns the key as the string used by Assimp.
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $(MaterialPropertyKey::$variant => $key,)*
+                    MaterialPropertyKey::Raw(ref key) => key
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for MaterialPropertyKey {
+            fn from(key: &str) -> MaterialPropertyKey {
+                match key {
+                    $($key => MaterialPropertyKey::$variant,)*
+                    _ => MaterialPropertyKey::Raw(key.to_owned())
+                }
+            }
+        }
+    )
+}
+
+material_property_keys! {
+    Name => "?mat.name",
+    TwoSided => "$mat.twosided",
+    ShadingModel => "$mat.shadingm",
+    Wireframe => "$mat.wireframe",
+    BlendMode => "$mat.blend",
+    Opacity => "$mat.opacity",
+    TransparencyFactor => "$mat.transparencyfactor",
+    BumpScaling => "$mat.bumpscaling",
+    Shininess => "$mat.shininess",
+    Reflectivity => "$mat.reflectivity",
+    ShininessStrength => "$mat.shinpercent",
+    IndexOfRefraction => "$mat.refracti",
+    DiffuseColor => "$clr.diffuse",
+    AmbientColor => "$clr.ambient",
+    SpecularColor => "$clr.specular",
+    EmissiveColor => "$clr.emissive",
+    TransparentColor => "$clr.transparent",
+    ReflectiveColor => "$clr.reflective",
+    GlobalBackground => "?bg.global",
+    GlobalShaderLanguage => "?sh.lang",
+    VertexShader => "?sh.vs",
+    FragmentShader => "?sh.fs",
+    GeometryShader => "?sh.gs",
+    TesselationShader => "?sh.ts",
+    PrimitiveShader => "?sh.ps",
+    ComputeShader => "?sh.cs",
+    /// Illumination model of Wavefront OBJ materials.
+    ObjIllum => "$mat.illum",
+
+    BaseColor => "$clr.base",
+    UseColorMap => "$mat.useColorMap",
+    UseMetallicMap => "$mat.useMetallicMap",
+    MetallicFactor => "$mat.metallicFactor",
+    UseRoughnessMap => "$mat.useRoughnessMap",
+    RoughnessFactor => "$mat.roughnessFactor",
+    AnisotropyFactor => "$mat.anisotropyFactor",
+    SpecularFactor => "$mat.specularFactor",
+    GlossinessFactor => "$mat.glossinessFactor",
+    SheenColorFactor => "$clr.sheen.factor",
+    SheenRoughnessFactor => "$mat.sheen.roughnessFactor",
+    ClearcoatFactor => "$mat.clearcoat.factor",
+    ClearcoatRoughnessFactor => "$mat.clearcoat.roughnessFactor",
+    TransmissionFactor => "$mat.transmission.factor",
+    VolumeThicknessFactor => "$mat.volume.thicknessFactor",
+    VolumeAttenuationDistance => "$mat.volume.attenuationDistance",
+    VolumeAttenuationColor => "$mat.volume.attenuationColor",
+    UseEmissiveMap => "$mat.useEmissiveMap",
+    EmissiveIntensity => "$mat.emissiveIntensity",
+    UseAoMap => "$mat.useAOMap",
+
+    GltfBaseColorFactor => "$mat.gltf.pbrMetallicRoughness.baseColorFactor",
+    GltfMetallicFactor => "$mat.gltf.pbrMetallicRoughness.metallicFactor",
+    GltfRoughnessFactor => "$mat.gltf.pbrMetallicRoughness.roughnessFactor",
+    GltfSpecularGlossiness => "$mat.gltf.pbrSpecularGlossiness",
+    GltfGlossinessFactor => "$mat.gltf.pbrMetallicRoughness.glossinessFactor",
+    GltfAlphaMode => "$mat.gltf.alphaMode",
+    GltfAlphaCutoff => "$mat.gltf.alphaCutoff",
+    GltfUnlit => "$mat.gltf.unlit",
+
+    TexturePath => "$tex.file",
+    TextureUvwSource => "$tex.uvwsrc",
+    TextureOp => "$tex.op",
+    TextureMapping => "$tex.mapping",
+    TextureBlend => "$tex.blend",
+    TextureMappingModeU => "$tex.mapmodeu",
+    TextureMappingModeV => "$tex.mapmodev",
+    TextureMapAxis => "$tex.mapaxis",
+    TextureUvTransform => "$tex.uvtrafo",
+    TextureFlags => "$tex.flags",
+    TextureMappingName => "$tex.mappingname",
+    TextureMappingId => "$tex.mappingid",
+    TextureMappingFilterMag => "$tex.mappingfiltermag",
+    TextureMappingFilterMin => "$tex.mappingfiltermin",
+    /// Scale of normal textures in glTF 2 materials.
+    TextureScale => "$tex.scale",
+    /// Strength of occlusion textures in glTF 2 materials.
+    TextureStrength => "$tex.strength",
+}
+
+impl AsRef<str> for MaterialPropertyKey {
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl fmt::Display for MaterialPropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
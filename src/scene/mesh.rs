@@ -1,10 +1,17 @@
+use std::ptr;
+#[cfg(feature = "assimp-5")]
+use std::os::raw::c_uint;
+
 use ffi::{AiMesh, AiVector3D, AiBone, AiVertexWeight, AiColor4D};
+use ffi::{AI_MAX_NUMBER_OF_COLOR_SETS, AI_MAX_NUMBER_OF_TEXTURECOORDS};
 
 use math::vector3::{Vector3D, Vector3DIter};
 use math::color4::{Color4D, Color4DIter};
 use super::face::{Face, FaceIter};
+use super::material::Material;
+use super::scene::Scene;
 
-use math::Matrix4x4;
+use math::{Aabb, BoundingSphere, Matrix4x4};
 
 define_type_and_iterator_indirect! {
     /// Mesh type (incomplete)
@@ -28,6 +35,21 @@ define_type_and_iterator_indirect! {
 }
 
 impl<'a> Mesh<'a> {
+    /// Returns the name of the mesh, which may be empty.
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Returns the index of the material of the mesh in the scene.
+    pub fn material_index(&self) -> u32 {
+        self.material_index
+    }
+
+    /// Returns the material of the mesh, or `None` if the scene doesn't have it.
+    pub fn material<'b>(&self, scene: &'b Scene) -> Option<Material<'b>> {
+        scene.material(self.material_index as usize)
+    }
+
     // TODO return as PrimitiveType enum
     pub fn primitive_types(&self) -> u32 {
         self.primitive_types
@@ -39,7 +61,7 @@ impl<'a> Mesh<'a> {
 
     pub fn vertex_iter(&self) -> Vector3DIter {
         Vector3DIter::new(self.vertices,
-                          self.num_vertices as usize)
+                          self.array_len(self.vertices))
     }
 
     pub fn get_vertex(&self, id: u32) -> Option<Vector3D> {
@@ -48,7 +70,7 @@ impl<'a> Mesh<'a> {
 
     pub fn normal_iter(&self) -> Vector3DIter {
         Vector3DIter::new(self.normals,
-                          self.num_vertices as usize)
+                          self.array_len(self.normals))
     }
 
     pub fn get_normal(&self, id: u32) -> Option<Vector3D> {
@@ -57,7 +79,7 @@ impl<'a> Mesh<'a> {
 
     pub fn tangent_iter(&self) -> Vector3DIter {
         Vector3DIter::new(self.tangents,
-                          self.num_vertices as usize)
+                          self.array_len(self.tangents))
     }
 
     pub fn get_tangent(&self, id: u32) -> Option<Vector3D> {
@@ -66,29 +88,108 @@ impl<'a> Mesh<'a> {
 
     pub fn bitangent_iter(&self) -> Vector3DIter {
         Vector3DIter::new(self.bitangents,
-                          self.num_vertices as usize)
+                          self.array_len(self.bitangents))
     }
 
     pub fn get_bitangent(&self, id: u32) -> Option<Vector3D> {
         self.vertex_data(self.bitangents, id)
     }
 
+    /// Returns the number of vertex color sets.
+    pub fn num_color_sets(&self) -> u32 {
+        self.colors.iter().take_while(|x| !x.is_null()).count() as u32
+    }
+
+    /// Returns true if the mesh has the given vertex color set.
+    pub fn has_vertex_colors(&self, set_id: usize) -> bool {
+        set_id < AI_MAX_NUMBER_OF_COLOR_SETS && !self.colors[set_id].is_null()
+    }
+
+    /// Returns an iterator over the vertex colors of a set, which is empty if the mesh doesn't
+    /// have the set.
     pub fn vertex_color_iter(&self, set_id: usize) -> Color4DIter {
-        Color4DIter::new(self.colors[set_id],
-                         self.num_vertices as usize)
+        let colors = self.color_set(set_id);
+        Color4DIter::new(colors,
+                         self.array_len(colors))
     }
 
     pub fn get_vertex_color(&self, set_id: usize, id: u32) -> Option<Color4D> {
-        self.color_data(self.colors[set_id], id)
+        self.color_data(self.color_set(set_id), id)
+    }
+
+    /// Returns the number of texture coordinate channels.
+    ///
+    /// Channel names aren't available, they require Assimp 5.1 or later.
+    pub fn num_uv_channels(&self) -> u32 {
+        self.texture_coords.iter().take_while(|x| !x.is_null()).count() as u32
+    }
+
+    /// Returns true if the mesh has the given texture coordinate channel.
+    pub fn has_texture_coords(&self, channel_id: usize) -> bool {
+        channel_id < AI_MAX_NUMBER_OF_TEXTURECOORDS && !self.texture_coords[channel_id].is_null()
+    }
+
+    /// Returns the number of components of the texture coordinates of a channel, or `None` if
+    /// the mesh doesn't have the channel.
+    ///
+    /// Texture coordinates are always returned as 3D vectors, but only this many components are
+    /// used. The others are zero, e.g. with 2 components `z` is always zero.
+    pub fn num_uv_components(&self, channel_id: usize) -> Option<u32> {
+        if self.has_texture_coords(channel_id) {
+            Some(self.num_uv_components[channel_id])
+        } else {
+            None
+        }
     }
 
+    /// Returns an iterator over the texture coordinates of a channel, which is empty if the
+    /// mesh doesn't have the channel.
     pub fn texture_coords_iter(&self, channel_id: usize) -> Vector3DIter {
-        Vector3DIter::new(self.texture_coords[channel_id],
-                          self.num_vertices as usize)
+        let coords = self.uv_channel(channel_id);
+        Vector3DIter::new(coords,
+                          self.array_len(coords))
     }
 
     pub fn get_texture_coord(&self, channel_id: usize, id: u32) -> Option<Vector3D> {
-        self.vertex_data(self.texture_coords[channel_id], id)
+        self.vertex_data(self.uv_channel(channel_id), id)
+    }
+
+    /// Returns the axis-aligned bounding box of the vertices, or `None` if the mesh has no
+    /// vertices.
+    ///
+    /// With the `assimp-5` feature, the box generated by the `gen_bounding_boxes` post-process
+    /// step is returned if it ran. Otherwise the box is computed from the vertices.
+    pub fn aabb(&self) -> Option<Aabb> {
+        if self.num_vertices == 0 {
+            return None;
+        }
+        self.generated_aabb().or_else(|| Aabb::from_points(self.vertex_iter()))
+    }
+
+    // Returns the box stored in `mAABB` by `gen_bounding_boxes`, which is all zeros if it
+    // didn't run.
+    #[cfg(feature = "assimp-5")]
+    fn generated_aabb(&self) -> Option<Aabb> {
+        // `mAABB` directly follows `mMethod`, the last member known to assimp-sys
+        let method = &self.method as *const c_uint;
+        let aabb = unsafe { &*(method.offset(1) as *const [AiVector3D; 2]) };
+        if aabb.iter().all(|x| x.x == 0.0 && x.y == 0.0 && x.z == 0.0) {
+            None
+        } else {
+            Some(Aabb::new(Vector3D::from_raw(&aabb[0]), Vector3D::from_raw(&aabb[1])))
+        }
+    }
+
+    #[cfg(not(feature = "assimp-5"))]
+    fn generated_aabb(&self) -> Option<Aabb> {
+        None
+    }
+
+    /// Returns a sphere containing all vertices, or `None` if the mesh has no vertices.
+    ///
+    /// See `BoundingSphere::from_points`.
+    pub fn bounding_sphere(&self) -> Option<BoundingSphere> {
+        BoundingSphere::from_points(self.vertex_iter())
     }
 
     pub fn num_faces(&self) -> u32 {
@@ -125,9 +226,27 @@ impl<'a> Mesh<'a> {
         }
     }
 
+    // Returns the color set, or null if the mesh doesn't have it.
+    #[inline]
+    fn color_set(&self, set_id: usize) -> *mut AiColor4D {
+        self.colors.get(set_id).cloned().unwrap_or(ptr::null_mut())
+    }
+
+    // Returns the texture coordinate channel, or null if the mesh doesn't have it.
+    #[inline]
+    fn uv_channel(&self, channel_id: usize) -> *mut AiVector3D {
+        self.texture_coords.get(channel_id).cloned().unwrap_or(ptr::null_mut())
+    }
+
+    // Optional vertex data is null when missing
+    #[inline]
+    fn array_len<T>(&self, array: *mut T) -> usize {
+        if array.is_null() { 0 } else { self.num_vertices as usize }
+    }
+
     #[inline]
     fn vertex_data(&self, array: *mut AiVector3D, id: u32) -> Option<Vector3D> {
-        if id < self.num_vertices {
+        if !array.is_null() && id < self.num_vertices {
             unsafe { Some(Vector3D::from_raw(array.offset(id as isize))) }
         } else {
             None
@@ -136,7 +255,7 @@ impl<'a> Mesh<'a> {
 
     #[inline]
     fn color_data(&self, array: *mut AiColor4D, id: u32) -> Option<Color4D> {
-        if id < self.num_vertices {
+        if !array.is_null() && id < self.num_vertices {
             unsafe { Some(Color4D::from_raw(array.offset(id as isize))) }
         } else {
             None
@@ -2,20 +2,39 @@
 
 pub use self::animation::*;
 pub use self::camera::*;
+pub use self::diff::{DiffOptions, Difference, KeyType, MeshAttribute, MeshCount, PropertyId, SceneDiff};
+pub use self::dump::DumpOptions;
 pub use self::face::*;
 pub use self::light::*;
 pub use self::material::*;
+pub use self::material_key::MaterialPropertyKey;
 pub use self::mesh::*;
 pub use self::node::*;
-pub use self::scene::Scene;
+pub use self::owned::OwnedScene;
+pub use self::pbr::*;
+pub use self::scene::{MemoryRequirements, Scene};
+pub use self::stats::{MeshStats, SceneStats, DEFAULT_TICKS_PER_SECOND};
 pub use self::texture::*;
+pub use self::texture_resolver::{ResolvedTexture, TextureResolver};
+
+// Re-exported so `Material::textures` and `TextureSlot` can be used without depending on
+// assimp-sys directly.
+pub use ffi::{AiTextureFlags, AiTextureMapMode, AiTextureMapping, AiTextureOp, AiTextureType};
 
 mod animation;
 mod camera;
+mod dependencies;
+mod diff;
+mod dump;
 mod face;
 mod light;
 mod material;
+mod material_key;
 mod mesh;
 mod node;
+mod owned;
+mod pbr;
 mod scene;
+mod stats;
 mod texture;
+mod texture_resolver;
@@ -50,6 +50,10 @@ impl<'a> Node<'a> {
     /// Returns a vector containing all of the meshes under this node. These are indices into
     /// the meshes contained in the `Scene` struct.
     pub fn meshes(&self) -> &[u32] {
+        // Nodes without meshes have a null pointer, which slices can't be made from
+        if self.meshes.is_null() {
+            return &[];
+        }
         let len = self.num_meshes as usize;
         unsafe { from_raw_parts(self.meshes, len) }
     }
@@ -0,0 +1,58 @@
+use std::fmt;
+use std::ops::Deref;
+
+use super::scene::Scene;
+
+/// A scene which owns its data and can be sent to other threads.
+///
+/// `Scene` can't be sent to other threads as it's a view into data owned by Assimp. The data
+/// isn't tied to any thread though, so a scene can be wrapped into an `OwnedScene` to move it
+/// to another thread, e.g. when importing in the background. It dereferences to `Scene` to
+/// access its contents.
+///
+/// # Examples
+/// ```
+/// use std::thread;
+/// use assimp::import::Importer;
+/// use assimp::scene::OwnedScene;
+///
+/// let scene = thread::spawn(|| {
+///     let importer = Importer::new();
+///     importer.read_file("examples/box.obj").map(OwnedScene::new)
+/// }).join().unwrap().unwrap();
+/// assert!(scene.num_meshes() > 0);
+/// ```
+pub struct OwnedScene(Scene<'static>);
+
+// The scene is only freed when dropped, and its data is only read through shared references.
+unsafe impl Send for OwnedScene {}
+unsafe impl Sync for OwnedScene {}
+
+impl OwnedScene {
+    /// Takes ownership of a scene returned by an `Importer`.
+    pub fn new(scene: Scene<'static>) -> OwnedScene {
+        OwnedScene(scene)
+    }
+
+    /// Returns the wrapped scene.
+    pub fn into_scene(self) -> Scene<'static> {
+        self.0
+    }
+}
+
+impl Deref for OwnedScene {
+    type Target = Scene<'static>;
+    fn deref(&self) -> &Scene<'static> { &self.0 }
+}
+
+impl From<Scene<'static>> for OwnedScene {
+    fn from(scene: Scene<'static>) -> OwnedScene {
+        OwnedScene(scene)
+    }
+}
+
+impl fmt::Debug for OwnedScene {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,235 @@
+use math::{Color3D, Color4D};
+use super::material::{Material, TextureSlot};
+
+// Raw `aiTextureType` values. The types from 12 on require Assimp 5.0 or later, older versions
+// use 12 for unknown textures as `AiTextureType` does. See `Material::has_assimp5_texture_types`.
+const TEXTURE_NONE: u32 = 0;
+const TEXTURE_DIFFUSE: u32 = 1;
+const TEXTURE_SPECULAR: u32 = 2;
+const TEXTURE_AMBIENT: u32 = 3;
+const TEXTURE_EMISSIVE: u32 = 4;
+const TEXTURE_HEIGHT: u32 = 5;
+const TEXTURE_NORMALS: u32 = 6;
+const TEXTURE_SHININESS: u32 = 7;
+const TEXTURE_OPACITY: u32 = 8;
+const TEXTURE_DISPLACEMENT: u32 = 9;
+const TEXTURE_LIGHTMAP: u32 = 10;
+const TEXTURE_REFLECTION: u32 = 11;
+const TEXTURE_BASE_COLOR: u32 = 12;
+const TEXTURE_NORMAL_CAMERA: u32 = 13;
+const TEXTURE_EMISSION_COLOR: u32 = 14;
+const TEXTURE_METALNESS: u32 = 15;
+const TEXTURE_DIFFUSE_ROUGHNESS: u32 = 16;
+const TEXTURE_AMBIENT_OCCLUSION: u32 = 17;
+const TEXTURE_UNKNOWN: u32 = 18;
+const TEXTURE_SHEEN: u32 = 19;
+const TEXTURE_CLEARCOAT: u32 = 20;
+const TEXTURE_TRANSMISSION: u32 = 21;
+const TEXTURE_UNKNOWN_ASSIMP4: u32 = 12;
+
+/// Returns the name of a raw `aiTextureType`, e.g. `"base_color"`.
+///
+/// `assimp5` tells whether the type is numbered as in Assimp 5, see
+/// `Material::has_assimp5_texture_types`. Otherwise types from 12 on are named `"unknown"`.
+pub fn texture_type_name(semantic: u32, assimp5: bool) -> &'static str {
+    match semantic {
+        _ if semantic >= TEXTURE_BASE_COLOR && !assimp5 => "unknown",
+        TEXTURE_NONE => "none",
+        TEXTURE_DIFFUSE => "diffuse",
+        TEXTURE_SPECULAR => "specular",
+        TEXTURE_AMBIENT => "ambient",
+        TEXTURE_EMISSIVE => "emissive",
+        TEXTURE_HEIGHT => "height",
+        TEXTURE_NORMALS => "normals",
+        TEXTURE_SHININESS => "shininess",
+        TEXTURE_OPACITY => "opacity",
+        TEXTURE_DISPLACEMENT => "displacement",
+        TEXTURE_LIGHTMAP => "lightmap",
+        TEXTURE_REFLECTION => "reflection",
+        TEXTURE_BASE_COLOR => "base_color",
+        TEXTURE_NORMAL_CAMERA => "normal_camera",
+        TEXTURE_EMISSION_COLOR => "emission_color",
+        TEXTURE_METALNESS => "metalness",
+        TEXTURE_DIFFUSE_ROUGHNESS => "diffuse_roughness",
+        TEXTURE_AMBIENT_OCCLUSION => "ambient_occlusion",
+        TEXTURE_SHEEN => "sheen",
+        TEXTURE_CLEARCOAT => "clearcoat",
+        TEXTURE_TRANSMISSION => "transmission",
+        _ => "unknown"
+    }
+}
+
+/// A material parameter, with the texture modulating it if there is one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PbrChannel<T> {
+    pub factor: T,
+    pub texture: Option<TextureSlot>
+}
+
+/// How the alpha of the base color is interpreted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AlphaMode {
+    /// Alpha is ignored and the material is fully opaque.
+    Opaque,
+    /// The material is either fully opaque or fully transparent depending on `alpha_cutoff`.
+    Mask,
+    /// Alpha is used for blending.
+    Blend
+}
+
+/// Metallic-roughness view of a material.
+///
+/// See `Material::pbr`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PbrMaterial {
+    /// Whether the material has PBR parameters. If false, all values were converted from the
+    /// legacy Phong parameters of the material.
+    pub is_pbr: bool,
+    pub base_color: PbrChannel<Color4D>,
+    pub metallic: PbrChannel<f32>,
+    pub roughness: PbrChannel<f32>,
+    pub normal: Option<TextureSlot>,
+    pub occlusion: Option<TextureSlot>,
+    pub emissive: PbrChannel<Color3D>,
+    /// Multiplier applied to the emissive color.
+    pub emissive_strength: f32,
+    pub clearcoat: PbrChannel<f32>,
+    pub clearcoat_roughness: PbrChannel<f32>,
+    pub sheen: PbrChannel<Color3D>,
+    pub sheen_roughness: PbrChannel<f32>,
+    pub transmission: PbrChannel<f32>,
+    pub alpha_mode: AlphaMode,
+    /// Alpha below which fragments are discarded when `alpha_mode` is `Mask`.
+    pub alpha_cutoff: f32,
+    pub double_sided: bool
+}
+
+impl<'a> Material<'a> {
+    /// Returns whether the texture types of the material are numbered as in Assimp 5.
+    ///
+    /// Assimp 5 added texture types from `aiTextureType_BASE_COLOR` (12) on, and renumbered
+    /// `aiTextureType_UNKNOWN` from 12 to 18. The raw types don't say which numbering they use,
+    /// so this checks for the PBR keys only written by Assimp 5 (`$clr.base`,
+    /// `$mat.metallicFactor` and `$mat.roughnessFactor`).
+    pub fn has_assimp5_texture_types(&self) -> bool {
+        self.get_color4(b"$clr.base\0").is_some() ||
+            self.get_float(b"$mat.metallicFactor\0").is_some() ||
+            self.get_float(b"$mat.roughnessFactor\0").is_some()
+    }
+
+    /// Returns a metallic-roughness view of the material.
+    ///
+    /// PBR parameters are read from the keys written by the glTF 2 and FBX importers of recent
+    /// Assimp versions (`$clr.base`, `$mat.metallicFactor`, ...), as well as the older
+    /// `$mat.gltf.pbrMetallicRoughness.*` keys. Materials without them are converted from their
+    /// Phong parameters: the diffuse color becomes the base color, the roughness is derived from
+    /// the shininess, and the material is considered non-metallic.
+    ///
+    /// Texture types from 12 on are only used if `has_assimp5_texture_types` returns true, since
+    /// older Assimp versions use 12 for unknown textures.
+    pub fn pbr(&self) -> PbrMaterial {
+        let assimp5 = self.has_assimp5_texture_types();
+        let find_texture = |candidates: &[(u32, u32)]| self.find_texture(candidates, assimp5);
+
+        let base_color = self.get_color4(b"$clr.base\0")
+            .or_else(|| self.get_color4(b"$mat.gltf.pbrMetallicRoughness.baseColorFactor\0"));
+        let metallic = self.get_float(b"$mat.metallicFactor\0")
+            .or_else(|| self.get_float(b"$mat.gltf.pbrMetallicRoughness.metallicFactor\0"));
+        let roughness = self.get_float(b"$mat.roughnessFactor\0")
+            .or_else(|| self.get_float(b"$mat.gltf.pbrMetallicRoughness.roughnessFactor\0"));
+        let is_pbr = base_color.is_some() || metallic.is_some() || roughness.is_some();
+
+        let opacity = self.get_float(b"$mat.opacity\0").unwrap_or(1.0);
+        let base_color = base_color.unwrap_or_else(|| {
+            let diffuse = self.get_color4(b"$clr.diffuse\0")
+                .unwrap_or(Color4D::new(1.0, 1.0, 1.0, 1.0));
+            Color4D::new(diffuse.r, diffuse.g, diffuse.b, diffuse.a * opacity)
+        });
+        let roughness = roughness.unwrap_or_else(|| {
+            shininess_to_roughness(self.get_float(b"$mat.shininess\0").unwrap_or(0.0))
+        });
+
+        // The glTF 2 importer stores the combined metallic-roughness texture as an unknown one
+        let metallic_roughness = if assimp5 {
+            find_texture(&[(TEXTURE_UNKNOWN, 0)])
+        } else {
+            self.texture_slot(TEXTURE_UNKNOWN_ASSIMP4, 0)
+        };
+        let alpha_mode = match self.get_string(b"$mat.gltf.alphaMode\0") {
+            Some(ref mode) if mode == "MASK" => AlphaMode::Mask,
+            Some(ref mode) if mode == "BLEND" => AlphaMode::Blend,
+            Some(_) => AlphaMode::Opaque,
+            None if base_color.a < 1.0 || find_texture(&[(TEXTURE_OPACITY, 0)]).is_some() => {
+                AlphaMode::Blend
+            }
+            None => AlphaMode::Opaque
+        };
+
+        PbrMaterial {
+            is_pbr: is_pbr,
+            base_color: PbrChannel {
+                factor: base_color,
+                // Older glTF 2 importers stored the base color texture as the second diffuse one
+                texture: find_texture(&[(TEXTURE_BASE_COLOR, 0), (TEXTURE_DIFFUSE, 1),
+                                             (TEXTURE_DIFFUSE, 0)])
+            },
+            metallic: PbrChannel {
+                factor: metallic.unwrap_or(if is_pbr { 1.0 } else { 0.0 }),
+                texture: find_texture(&[(TEXTURE_METALNESS, 0)])
+                    .or_else(|| metallic_roughness.clone())
+            },
+            roughness: PbrChannel {
+                factor: roughness,
+                texture: find_texture(&[(TEXTURE_DIFFUSE_ROUGHNESS, 0)])
+                    .or(metallic_roughness)
+            },
+            normal: find_texture(&[(TEXTURE_NORMAL_CAMERA, 0), (TEXTURE_NORMALS, 0)]),
+            occlusion: find_texture(&[(TEXTURE_AMBIENT_OCCLUSION, 0), (TEXTURE_LIGHTMAP, 0)]),
+            emissive: PbrChannel {
+                factor: self.get_color3(b"$clr.emissive\0").unwrap_or(Color3D::new(0.0, 0.0, 0.0)),
+                texture: find_texture(&[(TEXTURE_EMISSION_COLOR, 0), (TEXTURE_EMISSIVE, 0)])
+            },
+            emissive_strength: self.get_float(b"$mat.emissiveIntensity\0")
+                .or_else(|| self.get_float(b"$mat.gltf.emissiveStrength\0"))
+                .unwrap_or(1.0),
+            clearcoat: PbrChannel {
+                factor: self.get_float(b"$mat.clearcoat.factor\0").unwrap_or(0.0),
+                texture: find_texture(&[(TEXTURE_CLEARCOAT, 0)])
+            },
+            clearcoat_roughness: PbrChannel {
+                factor: self.get_float(b"$mat.clearcoat.roughnessFactor\0").unwrap_or(0.0),
+                texture: find_texture(&[(TEXTURE_CLEARCOAT, 1)])
+            },
+            sheen: PbrChannel {
+                factor: self.get_color3(b"$clr.sheen.factor\0").unwrap_or(Color3D::new(0.0, 0.0, 0.0)),
+                texture: find_texture(&[(TEXTURE_SHEEN, 0)])
+            },
+            sheen_roughness: PbrChannel {
+                factor: self.get_float(b"$mat.sheen.roughnessFactor\0").unwrap_or(0.0),
+                texture: find_texture(&[(TEXTURE_SHEEN, 1)])
+            },
+            transmission: PbrChannel {
+                factor: self.get_float(b"$mat.transmission.factor\0").unwrap_or(0.0),
+                texture: find_texture(&[(TEXTURE_TRANSMISSION, 0)])
+            },
+            alpha_mode: alpha_mode,
+            alpha_cutoff: self.get_float(b"$mat.gltf.alphaCutoff\0").unwrap_or(0.5),
+            double_sided: self.get_int(b"$mat.twosided\0").map_or(false, |x| x != 0)
+        }
+    }
+
+    // Returns the first of the given (semantic, index) textures that the material has, skipping
+    // types added in Assimp 5 unless the material uses its numbering.
+    fn find_texture(&self, candidates: &[(u32, u32)], assimp5: bool) -> Option<TextureSlot> {
+        candidates.iter()
+            .filter(|&&(semantic, _)| assimp5 || semantic < TEXTURE_BASE_COLOR)
+            .filter_map(|&(semantic, index)| self.texture_slot(semantic, index))
+            .next()
+    }
+}
+
+/// Converts a Phong specular exponent to a roughness, using the Blinn-Phong to Beckmann
+/// mapping `sqrt(2 / (n + 2))`.
+fn shininess_to_roughness(shininess: f32) -> f32 {
+    (2.0 / (shininess.max(0.0) + 2.0)).sqrt()
+}
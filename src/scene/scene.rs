@@ -1,5 +1,9 @@
+use std::mem;
+
 use ffi::*;
 
+use math::{Aabb, BoundingSphere, Matrix4x4};
+
 // Import all types
 use super::animation::*;
 use super::camera::*;
@@ -14,6 +18,22 @@ define_type! {
     struct Scene(&AiScene)
 }
 
+/// Memory used by the different parts of a scene, in bytes.
+///
+/// See `Scene::memory_requirements`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryRequirements {
+    pub textures: u32,
+    pub materials: u32,
+    pub meshes: u32,
+    pub nodes: u32,
+    pub animations: u32,
+    pub cameras: u32,
+    pub lights: u32,
+    /// Total memory used by the scene.
+    pub total: u32
+}
+
 impl<'a> Scene<'a> {
     /// Returns true if the imported scene is not complete.
     pub fn is_incomplete(&self) -> bool {
@@ -78,6 +98,15 @@ impl<'a> Scene<'a> {
                           self.num_materials as usize)
     }
 
+    /// Return an individual material from the scene.
+    pub fn material(&self, id: usize) -> Option<Material> {
+        if id < self.num_materials as usize {
+            unsafe { Some(Material::from_raw(*(self.materials.offset(id as isize)))) }
+        } else {
+            None
+        }
+    }
+
     /// Returns the number of animations in the scene.
     pub fn num_animations(&self) -> u32 {
         self.num_animations
@@ -130,6 +159,74 @@ impl<'a> Scene<'a> {
         CameraIter::new(self.cameras as *const *const AiCamera,
                         self.num_cameras as usize)
     }
+
+    /// Returns the world-space axis-aligned bounding box of the scene, or `None` if no node
+    /// references a mesh with vertices.
+    ///
+    /// The bounding box of each mesh is transformed by the world transformation of every node
+    /// referencing it, so meshes not referenced by any node are ignored.
+    pub fn bounds(&self) -> Option<Aabb> {
+        let mut bounds: Option<Aabb> = None;
+        self.visit_mesh_instances(&mut |mesh, transform| {
+            if let Some(aabb) = mesh.aabb() {
+                let aabb = aabb.transform(transform);
+                bounds = Some(bounds.map_or(aabb, |x| x.union(&aabb)));
+            }
+        });
+        bounds
+    }
+
+    /// Returns a world-space sphere containing all vertices of the scene, or `None` if no node
+    /// references a mesh with vertices.
+    ///
+    /// The sphere is centered on `bounds`.
+    pub fn bounding_sphere(&self) -> Option<BoundingSphere> {
+        let center = self.bounds()?.center();
+        let mut radius = 0.0f32;
+        self.visit_mesh_instances(&mut |mesh, transform| {
+            for p in mesh.vertex_iter() {
+                radius = radius.max((*transform * p - center).square_length());
+            }
+        });
+        Some(BoundingSphere::new(center, radius.sqrt()))
+    }
+
+    // Calls `f` with each mesh referenced by a node, and the world transformation of the node.
+    fn visit_mesh_instances<F>(&self, f: &mut F) where F: FnMut(Mesh, &Matrix4x4) {
+        fn visit<F>(scene: &Scene, node: Node, parent: &Matrix4x4, f: &mut F)
+            where F: FnMut(Mesh, &Matrix4x4)
+        {
+            let transform = *parent * node.transformation();
+            for &id in node.meshes() {
+                if let Some(mesh) = scene.mesh(id as usize) {
+                    f(mesh, &transform);
+                }
+            }
+            for child in node.child_iter() {
+                visit(scene, child, &transform, f);
+            }
+        }
+
+        if !self.root_node.is_null() {
+            visit(self, self.root_node(), &Matrix4x4::identity(), f);
+        }
+    }
+
+    /// Returns the amount of memory used by the scene, broken down by component.
+    pub fn memory_requirements(&self) -> MemoryRequirements {
+        let mut info: AiMemoryInfo = unsafe { mem::zeroed() };
+        unsafe { aiGetMemoryRequirements(self.0, &mut info) };
+        MemoryRequirements {
+            textures: info.textures,
+            materials: info.materials,
+            meshes: info.meshes,
+            nodes: info.nodes,
+            animations: info.animations,
+            cameras: info.cameras,
+            lights: info.lights,
+            total: info.total
+        }
+    }
 }
 
 // Drop implementation for a scene owned by Assimp.
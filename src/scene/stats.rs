@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::mesh::Mesh;
+use super::node::Node;
+use super::scene::Scene;
+
+/// Ticks per second assumed by Assimp when a file doesn't specify the animation speed.
+pub const DEFAULT_TICKS_PER_SECOND: f64 = 25.0;
+
+/// Statistics of a single mesh.
+///
+/// See `SceneStats::meshes`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeshStats {
+    pub name: String,
+    pub material_index: u32,
+    pub vertices: u32,
+    pub faces: u32,
+    pub triangles: u32,
+    /// Faces with more than three vertices.
+    pub polygons: u32,
+    pub lines: u32,
+    pub points: u32,
+    pub bones: u32,
+    pub uv_channels: u32,
+    pub color_sets: u32
+}
+
+impl MeshStats {
+    /// Computes the statistics of a mesh.
+    pub fn new(mesh: &Mesh) -> MeshStats {
+        let mut stats = MeshStats {
+            name: mesh.name().to_owned(),
+            material_index: mesh.material_index(),
+            vertices: mesh.num_vertices(),
+            faces: mesh.num_faces(),
+            bones: mesh.num_bones(),
+            uv_channels: mesh.num_uv_channels(),
+            color_sets: mesh.num_color_sets(),
+            ..Default::default()
+        };
+        for face in mesh.face_iter() {
+            match face.num_indices {
+                0 => (),
+                1 => stats.points += 1,
+                2 => stats.lines += 1,
+                3 => stats.triangles += 1,
+                _ => stats.polygons += 1
+            }
+        }
+        stats
+    }
+}
+
+/// Summary of the contents of a scene.
+///
+/// See `Scene::stats`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SceneStats {
+    pub vertices: u32,
+    pub faces: u32,
+    pub triangles: u32,
+    /// Faces with more than three vertices.
+    pub polygons: u32,
+    pub lines: u32,
+    pub points: u32,
+    /// Number of bones of all meshes.
+    pub bones: u32,
+    /// Number of distinct bone names, bones shared by several meshes are counted once.
+    pub unique_bones: u32,
+    /// Largest number of bones of a single mesh.
+    pub max_bones_per_mesh: u32,
+    pub materials: u32,
+    /// Textures stored in the scene.
+    pub embedded_textures: u32,
+    /// Distinct external texture files referenced by materials.
+    pub external_textures: u32,
+    pub animations: u32,
+    /// Total duration of all animations, in seconds.
+    pub animation_duration: f64,
+    pub cameras: u32,
+    pub lights: u32,
+    pub nodes: u32,
+    /// Depth of the node hierarchy, 1 if the scene only has a root node.
+    pub node_depth: u32,
+    /// Statistics of each mesh, in the order of `Scene::mesh_iter`. Its length is the number
+    /// of meshes.
+    pub meshes: Vec<MeshStats>
+}
+
+impl<'a> Scene<'a> {
+    /// Returns a summary of the contents of the scene.
+    ///
+    /// Counts are computed over the meshes of the scene, regardless of how many times they're
+    /// referenced by nodes.
+    pub fn stats(&self) -> SceneStats {
+        let meshes: Vec<MeshStats> = self.mesh_iter().map(|mesh| MeshStats::new(&mesh)).collect();
+
+        let mut bone_names = HashSet::new();
+        for mesh in self.mesh_iter() {
+            for bone in mesh.bone_iter() {
+                bone_names.insert(bone.name().to_owned());
+            }
+        }
+
+        let animation_duration = self.animation_iter().map(|anim| {
+            let ticks_per_second = if anim.ticks_per_second > 0.0 {
+                anim.ticks_per_second
+            } else {
+                DEFAULT_TICKS_PER_SECOND
+            };
+            anim.duration / ticks_per_second
+        }).sum();
+
+        let (nodes, node_depth) = if self.root_node.is_null() {
+            (0, 0)
+        } else {
+            count_nodes(&self.root_node())
+        };
+
+        SceneStats {
+            vertices: meshes.iter().map(|x| x.vertices).sum(),
+            faces: meshes.iter().map(|x| x.faces).sum(),
+            triangles: meshes.iter().map(|x| x.triangles).sum(),
+            polygons: meshes.iter().map(|x| x.polygons).sum(),
+            lines: meshes.iter().map(|x| x.lines).sum(),
+            points: meshes.iter().map(|x| x.points).sum(),
+            bones: meshes.iter().map(|x| x.bones).sum(),
+            unique_bones: bone_names.len() as u32,
+            max_bones_per_mesh: meshes.iter().map(|x| x.bones).max().unwrap_or(0),
+            materials: self.num_materials(),
+            embedded_textures: self.num_textures(),
+            external_textures: self.texture_dependencies().len() as u32,
+            animations: self.num_animations(),
+            animation_duration: animation_duration,
+            cameras: self.num_cameras(),
+            lights: self.num_lights(),
+            nodes: nodes,
+            node_depth: node_depth,
+            meshes: meshes
+        }
+    }
+}
+
+// Returns the number of nodes in the hierarchy under `node`, and its depth.
+fn count_nodes(node: &Node) -> (u32, u32) {
+    node.child_iter().fold((1, 1), |(count, depth), child| {
+        let (child_count, child_depth) = count_nodes(&child);
+        (count + child_count, depth.max(child_depth + 1))
+    })
+}
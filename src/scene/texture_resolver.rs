@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::material::PropertyValue;
+use super::material_key::MaterialPropertyKey;
+use super::scene::Scene;
+
+/// Location of a texture referenced by a material.
+///
+/// See `TextureResolver::resolve`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolvedTexture {
+    /// The texture is stored in the file at the given path.
+    File(PathBuf),
+    /// The texture is embedded in the scene, at the given index of `Scene::texture_iter`.
+    Embedded(usize),
+    /// No file matching the reference could be found.
+    Missing
+}
+
+/// Maps the texture paths stored in materials to files on disk.
+///
+/// Texture paths are stored as written by the tool which exported the model, and are often
+/// unusable as is: they may be absolute paths on the exporting machine, use Windows separators,
+/// or differ in case from the actual file names. The resolver tries, in order:
+///
+/// 1. the path as is, if it's absolute,
+/// 2. the path relative to the directory of the model, then to each search directory,
+/// 3. the same with leading directories of the path removed one at a time, down to the file
+///    name alone.
+///
+/// Unless disabled with `case_sensitive`, paths which don't exist are also matched against
+/// files differing only in case.
+///
+/// # Examples
+/// ```no_run
+/// use assimp::Importer;
+/// use assimp::scene::{ResolvedTexture, TextureResolver};
+///
+/// let importer = Importer::new();
+/// let scene = importer.read_file("models/house.obj").unwrap();
+///
+/// let mut resolver = TextureResolver::new("models/house.obj");
+/// resolver.add_search_dir("textures");
+/// for reference in scene.texture_dependencies() {
+///     if resolver.resolve(&reference) == ResolvedTexture::Missing {
+///         println!("missing texture: {}", reference);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextureResolver {
+    model_dir: PathBuf,
+    search_dirs: Vec<PathBuf>,
+    case_sensitive: bool
+}
+
+impl TextureResolver {
+    /// Creates a resolver for the textures of the model at the given path.
+    pub fn new<P: AsRef<Path>>(model_path: P) -> TextureResolver {
+        let model_dir = model_path.as_ref().parent().map(|x| x.to_path_buf()).unwrap_or_default();
+        TextureResolver {
+            model_dir: model_dir,
+            search_dirs: Vec::new(),
+            case_sensitive: false
+        }
+    }
+
+    /// Adds a directory to look for textures in, after the directory of the model.
+    pub fn add_search_dir<P: AsRef<Path>>(&mut self, dir: P) {
+        self.search_dirs.push(dir.as_ref().to_path_buf());
+    }
+
+    /// Only match files whose path has the exact case of the reference.
+    pub fn case_sensitive(&mut self, state: bool) {
+        self.case_sensitive = state;
+    }
+
+    /// Resolves a texture path stored in a material.
+    ///
+    /// References to embedded textures (`*<n>`) are returned as is, without checking that the
+    /// scene has that many textures.
+    pub fn resolve(&self, reference: &str) -> ResolvedTexture {
+        if let Some(index) = reference.strip_prefix('*') {
+            return match index.parse() {
+                Ok(index) => ResolvedTexture::Embedded(index),
+                Err(_) => ResolvedTexture::Missing
+            };
+        }
+
+        let normalized = reference.trim().replace('\\', "/");
+        if normalized.is_empty() {
+            return ResolvedTexture::Missing;
+        }
+        let path = Path::new(&normalized);
+
+        if path.is_absolute() && path.is_file() {
+            return ResolvedTexture::File(path.to_path_buf());
+        }
+
+        let dirs: Vec<&Path> = Some(self.model_dir.as_path()).into_iter()
+            .chain(self.search_dirs.iter().map(|x| x.as_path()))
+            .collect();
+
+        // Absolute paths, including Windows paths with a drive letter, are tried relative to
+        // each directory without their root. Leading directories are then dropped one at a
+        // time, down to the file name alone.
+        let relative = relative_part(&normalized);
+        let suffixes = relative.match_indices('/').map(|(idx, _)| &relative[idx + 1..]);
+        for path in Some(relative).into_iter().chain(suffixes).filter(|x| !x.is_empty()) {
+            for dir in &dirs {
+                if let Some(found) = self.find(dir, Path::new(path)) {
+                    return ResolvedTexture::File(found);
+                }
+            }
+        }
+        ResolvedTexture::Missing
+    }
+
+    /// Resolves all textures referenced by the materials of a scene.
+    ///
+    /// Returns each reference with its location, in the order they're first referenced.
+    /// Unlike `Scene::texture_dependencies`, this includes embedded textures, which are reported as
+    /// missing if the scene doesn't have them.
+    pub fn resolve_scene(&self, scene: &Scene) -> Vec<(String, ResolvedTexture)> {
+        scene.texture_references().into_iter()
+            .map(|reference| {
+                let resolved = match self.resolve(&reference) {
+                    ResolvedTexture::Embedded(index) if index >= scene.num_textures() as usize => {
+                        ResolvedTexture::Missing
+                    }
+                    resolved => resolved
+                };
+                (reference, resolved)
+            })
+            .collect()
+    }
+
+    fn find(&self, dir: &Path, path: &Path) -> Option<PathBuf> {
+        let joined = dir.join(path);
+        if joined.is_file() {
+            Some(joined)
+        } else if !self.case_sensitive {
+            find_case_insensitive(dir, path)
+        } else {
+            None
+        }
+    }
+}
+
+// Strips the root of absolute paths, e.g. `C:/textures/a.png` becomes `textures/a.png`.
+fn relative_part(path: &str) -> &str {
+    let bytes = path.as_bytes();
+    let path = if bytes.len() >= 2 && bytes[1] == b':' && (bytes[0] as char).is_ascii_alphabetic() {
+        &path[2..]
+    } else {
+        path
+    };
+    path.trim_start_matches('/')
+}
+
+fn find_case_insensitive(dir: &Path, path: &Path) -> Option<PathBuf> {
+    let mut current = if dir.as_os_str().is_empty() { PathBuf::from(".") } else { dir.to_path_buf() };
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => {
+                let name = name.to_str()?;
+                let entry = fs::read_dir(&current).ok()?
+                    .filter_map(|entry| entry.ok())
+                    .find(|entry| entry.file_name().to_str().map_or(false, |x| x.eq_ignore_ascii_case(name)))?;
+                current = entry.path();
+            }
+            Component::ParentDir => current.push(".."),
+            _ => ()
+        }
+    }
+    if current.is_file() { Some(current) } else { None }
+}
+
+impl<'a> Scene<'a> {
+    /// Returns the external texture files needed by the scene, as referenced by its materials.
+    ///
+    /// References to embedded textures are skipped. Each file is listed once, in the order it's
+    /// first referenced. Paths are returned as stored in the model, use `TextureResolver` to
+    /// find the actual files.
+    ///
+    /// Other files read by the importer, such as `.mtl` material libraries or glTF `.bin`
+    /// buffers, aren't recorded in the scene. Use `dependencies` to list them too.
+    pub fn texture_dependencies(&self) -> Vec<String> {
+        self.texture_references().into_iter().filter(|x| !x.starts_with('*')).collect()
+    }
+
+    // Returns the unique texture paths of all materials, in order of appearance.
+    fn texture_references(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut references = Vec::new();
+        for material in self.material_iter() {
+            for prop in material.properties_iter() {
+                if prop.key() != MaterialPropertyKey::TexturePath {
+                    continue;
+                }
+                if let PropertyValue::String(path) = prop.value() {
+                    if !path.is_empty() && seen.insert(path.clone()) {
+                        references.push(path);
+                    }
+                }
+            }
+        }
+        references
+    }
+}
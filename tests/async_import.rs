@@ -0,0 +1,77 @@
+#![cfg(feature = "async")]
+extern crate assimp;
+
+use std::future::Future;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+use assimp::import::{ImportError, Importer};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Minimal executor, polling the future on the current thread until it completes
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park()
+        }
+    }
+}
+
+#[test]
+fn test_read_file_async() {
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    let futures: Vec<_> = (0..4).map(|_| importer.read_file_async("examples/spider.obj")).collect();
+    drop(importer);
+
+    let scenes: Vec<_> = futures.into_iter().map(|x| block_on(x).unwrap()).collect();
+    let expected = Importer::new().read_file("examples/spider.obj").unwrap();
+    for scene in &scenes {
+        assert_eq!(scene.num_meshes(), expected.num_meshes());
+    }
+
+    // Scenes can be moved to other threads
+    let meshes = thread::spawn(move || scenes[0].num_meshes()).join().unwrap();
+    assert_eq!(meshes, expected.num_meshes());
+}
+
+#[test]
+fn test_read_file_async_errors() {
+    let importer = Importer::new();
+    match block_on(importer.read_file_async("examples/missing.obj")) {
+        Err(ImportError::Failed(error)) => assert!(!error.is_empty()),
+        other => panic!("unexpected result {:?}", other.map(|_| ()))
+    }
+
+    let future = importer.read_file_async("examples/box.obj");
+    future.cancel();
+    assert_eq!(block_on(future).err(), Some(ImportError::Cancelled));
+}
+
+#[test]
+fn test_read_file_async_properties() {
+    use assimp::import::ConfigKey;
+
+    // Properties set directly, rather than through the settings, are used as well
+    let mut importer = Importer::new();
+    importer.split_large_meshes(|x| x.enable = true);
+    importer.set_property_int(ConfigKey::PpSlmVertexLimit, 4);
+    let scene = block_on(importer.read_file_async("examples/box.obj")).unwrap();
+    assert_eq!(scene.num_meshes(), 6);
+
+    // So are they in clones of the importer
+    let scene = importer.clone().read_file("examples/box.obj").unwrap();
+    assert_eq!(scene.num_meshes(), 6);
+}
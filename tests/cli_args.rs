@@ -0,0 +1,93 @@
+#![cfg(feature = "cli")]
+// Tests of the argument parser of the assimp-rs tool, which is compiled in from the binary's
+// sources since binaries can't be linked to.
+extern crate assimp;
+
+#[path = "../src/bin/assimp-rs/args.rs"]
+#[allow(dead_code)]
+mod args;
+
+use args::{Arg, Parser, PostProcessArgs};
+use assimp::import::structs::ComponentType;
+
+fn new_parser(args: &[&str]) -> Parser {
+    Parser::new(args.iter().map(|x| x.to_string()))
+}
+
+fn post_process(args: &[&str]) -> Result<PostProcessArgs, String> {
+    let mut parser = new_parser(args);
+    let mut post_process = PostProcessArgs::new();
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Arg::Option(name) => {
+                if !post_process.parse(&name, &mut parser)? {
+                    return Err(format!("unknown option --{}", name));
+                }
+            }
+            Arg::Positional(arg) => return Err(format!("unexpected argument '{}'", arg))
+        }
+    }
+    Ok(post_process)
+}
+
+#[test]
+fn test_parser() {
+    let mut parser = new_parser(&["--tolerance=0.5", "--json", "a.obj", "--out", "b.obj", "--",
+                                  "--c"]);
+    assert_eq!(parser.next(), Ok(Some(Arg::Option("tolerance".to_owned()))));
+    assert_eq!(parser.parse_value::<f32>("tolerance"), Ok(0.5));
+    assert_eq!(parser.next(), Ok(Some(Arg::Option("json".to_owned()))));
+    assert_eq!(parser.next(), Ok(Some(Arg::Positional("a.obj".to_owned()))));
+    assert_eq!(parser.next(), Ok(Some(Arg::Option("out".to_owned()))));
+    assert_eq!(parser.value("out"), Ok("b.obj".to_owned()));
+    assert_eq!(parser.next(), Ok(Some(Arg::Positional("--c".to_owned()))));
+    assert_eq!(parser.next(), Ok(None));
+}
+
+#[test]
+fn test_parser_errors() {
+    // Values given with `=` must be read by the option
+    let mut parser = new_parser(&["--json=yes", "a.obj"]);
+    assert_eq!(parser.next(), Ok(Some(Arg::Option("json".to_owned()))));
+    assert_eq!(parser.next(), Err("unexpected value 'yes'".to_owned()));
+
+    let mut parser = new_parser(&["--out"]);
+    assert_eq!(parser.next(), Ok(Some(Arg::Option("out".to_owned()))));
+    assert_eq!(parser.value("out"), Err("missing value for --out".to_owned()));
+
+    let mut parser = new_parser(&["--tolerance", "small"]);
+    assert_eq!(parser.next(), Ok(Some(Arg::Option("tolerance".to_owned()))));
+    assert_eq!(parser.parse_value::<f32>("tolerance"),
+               Err("invalid value for --tolerance: 'small'".to_owned()));
+}
+
+#[test]
+fn test_post_process_args() {
+    let args = post_process(&["--preset", "fast", "--triangulate", "--global-scale=2",
+                              "--force-gen-normals", "--remove-component", "colors,lights,colors"])
+        .unwrap();
+    let settings = args.settings();
+    assert!(settings.triangulate);
+    assert!(settings.generate_normals.enable && settings.generate_normals.force);
+    assert!(settings.global_scale.enable);
+    assert_eq!(settings.global_scale.scale_factor, 2.0);
+    assert!(settings.remove_component.enable);
+    assert_eq!(settings.remove_component.components,
+               vec![ComponentType::Colors, ComponentType::Lights]);
+
+    let settings = post_process(&["--drop-normals", "--debone", "--populate-armature-data",
+                                  "--gen-bounding-boxes"]).unwrap().settings().clone();
+    assert!(settings.drop_normals && settings.debone.enable);
+    assert!(settings.populate_armature_data && settings.gen_bounding_boxes);
+}
+
+#[test]
+fn test_post_process_args_errors() {
+    assert_eq!(post_process(&["--triangulate", "--preset", "fast"]).err(),
+               Some("presets and profiles must be given before other post-processing options"
+                   .to_owned()));
+    assert_eq!(post_process(&["--preset", "slow"]).err(), Some("unknown preset 'slow'".to_owned()));
+    assert_eq!(post_process(&["--remove-component", "normals,wings"]).err(),
+               Some("unknown component 'wings'".to_owned()));
+    assert_eq!(post_process(&["--json"]).err(), Some("unknown option --json".to_owned()));
+}
@@ -0,0 +1,45 @@
+extern crate assimp;
+
+use std::env;
+use std::fs;
+
+use assimp::export::{ExportFormat, Exporter};
+use assimp::import::Importer;
+
+#[test]
+fn test_export_formats() {
+    let formats = ExportFormat::all();
+    assert!(!formats.is_empty());
+    let obj = ExportFormat::from_id("obj").unwrap();
+    assert_eq!(obj.extension, "obj");
+    assert_eq!(ExportFormat::for_file("models/box.OBJ").unwrap().extension, "obj");
+    assert!(ExportFormat::from_id("not a format").is_none());
+    assert!(Exporter::new("not a format").is_none());
+}
+
+#[test]
+fn test_export_file() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let exporter = Exporter::new("obj").unwrap();
+
+    let path = env::temp_dir().join("assimp-rs-test-export.obj");
+    let path = path.to_str().unwrap();
+    exporter.export_file(&scene, path).unwrap();
+
+    let exported = importer.read_file(path).unwrap();
+    assert_eq!(exported.num_meshes(), scene.num_meshes());
+    assert_eq!(exported.stats().faces, scene.stats().faces);
+    let _ = fs::remove_file(path);
+    let _ = fs::remove_file(path.replace(".obj", ".mtl"));
+}
+
+#[test]
+fn test_export_to_memory() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let blobs = Exporter::new("obj").unwrap().export_to_memory(&scene).unwrap();
+    assert_eq!(blobs[0].name, "");
+    assert!(!blobs[0].data.is_empty());
+    assert!(String::from_utf8_lossy(&blobs[0].data).contains("\nv "));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "glam")]
+extern crate assimp;
+extern crate glam;
+
+use assimp::math::*;
+
+#[test]
+fn test_matrix3_conversion() {
+    use glam::Mat3;
+    // glam matrices are column-major
+    let m1 = Mat3::from_cols_array(&[1.0, 2.0, 3.0,
+                                     4.0, 5.0, 6.0,
+                                     7.0, 8.0, 9.0]);
+    let m2 = Matrix3x3::new(1.0, 4.0, 7.0,
+                            2.0, 5.0, 8.0,
+                            3.0, 6.0, 9.0);
+
+    let m3 = Matrix3x3::from(m1);
+    let m4: Mat3 = m2.into();
+
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+}
+
+#[test]
+fn test_matrix4_conversion() {
+    use glam::Mat4;
+    let m1 = Mat4::from_cols_array(&[1.0, 2.0, 3.0, 4.0,
+                                     5.0, 6.0, 7.0, 8.0,
+                                     9.0, 10.0, 11.0, 12.0,
+                                     13.0, 14.0, 15.0, 16.0]);
+    let m2 = Matrix4x4::new(1.0, 5.0, 9.0, 13.0,
+                            2.0, 6.0, 10.0, 14.0,
+                            3.0, 7.0, 11.0, 15.0,
+                            4.0, 8.0, 12.0, 16.0);
+
+    let m3 = Matrix4x4::from(m1);
+    let m4: Mat4 = m2.into();
+
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+}
+
+#[test]
+fn test_quaternion_conversion() {
+    use glam::Quat;
+
+    let q1 = Quat::from_xyzw(2.0, 3.0, 4.0, 1.0);
+    let q2 = Quaternion::from(q1);
+    let q3: Quat = q2.into();
+    assert_eq!(q2, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(q1, q3);
+}
+
+#[test]
+fn test_vector2_conversion() {
+    use glam::Vec2;
+
+    let v1 = Vec2::new(1.0, 2.0);
+    let v2 = Vector2D::from(v1);
+    let v3: Vec2 = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_vector3_conversion() {
+    use glam::Vec3;
+
+    let v1 = Vec3::new(1.0, 2.0, 3.0);
+    let v2 = Vector3D::from(v1);
+    let v3: Vec3 = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_color3_conversion() {
+    use glam::Vec3;
+
+    let v1 = Vec3::new(1.0, 2.0, 3.0);
+    let v2 = Color3D::from(v1);
+    let v3: Vec3 = v2.into();
+    assert_eq!(v2, Color3D::new(1.0, 2.0, 3.0));
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_color4_conversion() {
+    use glam::Vec4;
+
+    let v1 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let v2 = Color4D::from(v1);
+    let v3: Vec4 = v2.into();
+    assert_eq!(v2, Color4D::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(v1, v3);
+}
@@ -39,3 +39,225 @@ fn test_sort_by_primitive_type_panic() {
     let all = vec![Point, Line, Triangle, Polygon];
     importer.sort_by_primitive_type(|x| { x.enable = true; x.remove = all.clone() });
 }
+
+#[test]
+fn test_settings_round_trip() {
+    use assimp::import::ImportSettings;
+    use assimp::import::structs::LwoLayer;
+
+    let mut settings = ImportSettings::target_realtime_max_quality().convert_to_left_handed();
+    settings.fbx_read_lights = false;
+    settings.md3_skin_name = Some("red".to_owned());
+    settings.lwo_one_layer_only = Some(LwoLayer::Index(2));
+
+    let importer = Importer::with_settings(&settings);
+    assert_eq!(importer.settings(), &settings);
+
+    let scene = importer.read_file("examples/box.obj");
+    assert!(scene.is_ok());
+}
+
+#[test]
+fn test_settings_track_configuration_methods() {
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    importer.generate_normals(|x| { x.enable = true; x.smooth = true; });
+    importer.fbx_read_cameras(false);
+    importer.global_keyframe(3);
+
+    let settings = importer.settings();
+    assert!(settings.triangulate);
+    assert!(settings.generate_normals.enable);
+    assert!(settings.generate_normals.smooth);
+    assert!(!settings.fbx_read_cameras);
+    assert_eq!(settings.global_keyframe, Some(3));
+}
+
+#[test]
+fn test_newer_postprocess_steps() {
+    let mut importer = Importer::new();
+    importer.global_scale(|x| { x.enable = true; x.scale_factor = 0.01; });
+    importer.generate_normals(|x| { x.enable = true; x.force = true; });
+    importer.drop_normals(true);
+    importer.gen_bounding_boxes(true);
+    importer.embed_textures(true);
+    importer.populate_armature_data(true);
+
+    let settings = importer.settings().clone();
+    assert_eq!(settings.global_scale.scale_factor, 0.01);
+    assert!(settings.generate_normals.force);
+    assert!(settings.gen_bounding_boxes);
+
+    // Older versions of Assimp ignore steps they don't know about
+    let scene = importer.read_file("examples/box.obj");
+    assert!(scene.is_ok());
+
+    importer.gen_bounding_boxes(false);
+    assert!(!importer.settings().gen_bounding_boxes);
+
+    importer.convert_to_left_handed(true);
+    let settings = importer.settings();
+    assert!(settings.make_left_handed && settings.flip_uvs && settings.flip_winding_order);
+}
+
+#[test]
+fn test_set_property_generic() {
+    use assimp::import::ConfigKey;
+
+    let mut importer = Importer::new();
+    importer.remove_redudant_materials(|x| x.enable = true);
+    importer.set_property_string(ConfigKey::PpRrmExcludeList, "'DefaultMaterial'");
+    importer.set_property_int(ConfigKey::PpLbwMaxWeights, 2);
+    importer.set_property_float("PP_GSN_MAX_SMOOTHING_ANGLE", 80.0);
+    importer.set_property_matrix(ConfigKey::PpPtvRootTransformation, assimp::Matrix4x4::identity());
+
+    assert_eq!(ConfigKey::PpRrmExcludeList.as_str(), "PP_RRM_EXCLUDE_LIST");
+    assert_eq!(ConfigKey::GlobalScaleFactor.as_str(), "GLOBAL_SCALE_FACTOR");
+    assert_eq!(ConfigKey::ImportIfcSmoothingAngle.as_str(), "IMPORT_IFC_SMOOTHING_ANGLE");
+    assert_eq!(ConfigKey::ExportXfile64Bit.as_str(), "EXPORT_XFILE_64BIT");
+
+    let scene = importer.read_file("examples/box.obj");
+    assert!(scene.is_ok());
+}
+
+#[test]
+fn test_read_file_with_report() {
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    importer.join_identical_vertices(true);
+
+    let (scene, report) = importer.read_file_with_report("examples/box.obj").unwrap();
+    assert!(report.total_time >= report.postprocess_time());
+    assert!(report.steps.len() >= 2);
+    assert_eq!(report.memory, scene.memory_requirements());
+    assert!(report.memory.total > 0);
+
+    // Time measurements are restored afterwards
+    assert!(!importer.settings().measure_time);
+}
+
+#[test]
+fn test_read_file_with_log() {
+    use assimp::log::Severity;
+    use std::thread;
+
+    let (scene, messages) = Importer::new().read_file_with_log("examples/box.obj");
+    assert!(scene.is_ok());
+    assert!(messages.iter().all(|x| x.severity >= Severity::Warn));
+
+    // Failed imports on other threads don't leak into each other's log
+    let handles: Vec<_> = (0..4).map(|i| thread::spawn(move || {
+        let file = format!("examples/missing{}.obj", i);
+        let (scene, messages) = Importer::new().read_file_with_log(&file);
+        assert!(scene.err().unwrap().contains(&file));
+        assert!(messages.iter().all(|x| !x.text.contains("missing") || x.text.contains(&file)));
+    })).collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+#[test]
+fn test_can_read() {
+    assert!(Importer::can_read("obj"));
+    assert!(Importer::can_read(".obj"));
+    assert!(Importer::can_read("examples/box.OBJ"));
+    assert!(!Importer::can_read("examples/notes.txt"));
+    assert!(!Importer::can_read(""));
+}
+
+#[test]
+fn test_importer_descriptions() {
+    use assimp::import::ImporterDescription;
+
+    let descriptions: Vec<_> = Importer::importer_descriptions().collect();
+    assert!(descriptions.len() > 10);
+    assert!(descriptions.iter().all(|x| !x.name.is_empty() && !x.extensions.is_empty()));
+
+    let obj = ImporterDescription::for_file("examples/box.obj").unwrap();
+    assert!(obj.extensions.contains(&"obj".to_owned()));
+    assert!(obj.supports_text);
+    assert!(descriptions.contains(&obj));
+
+    assert!(ImporterDescription::for_file("txt").is_none());
+}
+
+#[test]
+fn test_import_batch() {
+    use assimp::import::{import_batch, import_batch_with_progress, BatchOptions, ImportError, ImportSettings};
+
+    let paths = ["examples/spider.obj", "examples/missing.obj", "examples/box.obj"];
+    let mut settings = ImportSettings::default();
+    settings.triangulate = true;
+
+    let mut reported = Vec::new();
+    let results = import_batch_with_progress(&paths, &settings, &BatchOptions { concurrency: 2 }, |x| {
+        assert_eq!(x.total, paths.len());
+        reported.push((x.index, x.success, x.completed, x.failed));
+    });
+    assert_eq!(results.len(), paths.len());
+
+    // Results are in input order, progress in completion order
+    let importer = Importer::with_settings(&settings);
+    for (path, result) in paths.iter().zip(&results) {
+        match importer.read_file(path) {
+            Ok(scene) => assert_eq!(result.as_ref().unwrap().num_meshes(), scene.num_meshes()),
+            Err(_) => match *result {
+                Err(ImportError::Failed(ref error)) => assert!(!error.is_empty()),
+                _ => panic!("expected {} to fail", path)
+            }
+        }
+    }
+    reported.sort();
+    assert_eq!(reported.iter().map(|x| x.0).collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!(reported.iter().filter(|x| !x.1).count(), 1);
+    assert_eq!(reported.iter().map(|x| x.2).max(), Some(3));
+
+    assert!(import_batch::<&str>(&[], &settings).is_empty());
+}
+
+#[test]
+fn test_import_batch_each() {
+    use assimp::import::{import_batch_each, BatchOptions, ImportSettings};
+    use assimp::log::Severity;
+
+    let paths = ["examples/box.obj", "examples/missing.obj"];
+    let options = BatchOptions { concurrency: 2 };
+    let mut results = Vec::new();
+    import_batch_each(&paths, &ImportSettings::default(), &options, |x, result| {
+        assert_eq!(x.success, result.scene.is_ok());
+        results.push((x.index, result.scene.map(|scene| scene.num_meshes()), result.messages));
+    });
+    results.sort_by_key(|x| x.0);
+    assert_eq!(results.len(), 2);
+
+    assert_eq!(results[0].1, Ok(1));
+    // The error of each file is the one logged by its own import
+    let error = results[1].1.clone().unwrap_err().to_string();
+    assert!(results[1].2.iter().any(|msg| msg.severity == Severity::Error && msg.text == error));
+}
+
+#[test]
+fn test_import_batch_process() {
+    use assimp::import::{import_batch_process, BatchOptions, ImportSettings};
+    use std::thread;
+
+    let paths = ["examples/box.obj", "examples/missing.obj", "examples/spider.obj"];
+    let caller = thread::current().id();
+    let mut results = Vec::new();
+    import_batch_process(&paths, &ImportSettings::default(), &BatchOptions { concurrency: 2 },
+                         |index, result| {
+        // Results are processed on the worker which imported them
+        assert!(thread::current().id() != caller);
+        (index, result.scene.map(|scene| scene.num_meshes()).ok())
+    }, |x, output| {
+        assert_eq!(x.index, output.0);
+        assert_eq!(x.success, output.1.is_some());
+        results.push(output);
+    });
+    results.sort();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].1, Some(1));
+    assert_eq!(results[1].1, None);
+    assert!(results[2].1.is_some());
+}
@@ -18,3 +18,61 @@ fn test_custom_logging() {
     let importer = Importer::new();
     let scene = importer.read_file("examples/box.obj");
 }
+
+#[test]
+fn test_split_message() {
+    use assimp::log::Severity;
+
+    assert_eq!(Severity::split_message("Info,  T0: Load examples/box.obj\n"),
+               (Severity::Info, "Load examples/box.obj"));
+    assert_eq!(Severity::split_message("Warn,  T1: Something: odd"),
+               (Severity::Warn, "Something: odd"));
+    assert_eq!(Severity::split_message("Error, T0: Failed"), (Severity::Error, "Failed"));
+    assert_eq!(Severity::split_message("Debug, T0: END   `import`"),
+               (Severity::Debug, "END   `import`"));
+    assert_eq!(Severity::split_message("unprefixed"), (Severity::Info, "unprefixed"));
+    assert!(Severity::Debug < Severity::Info && Severity::Warn < Severity::Error);
+}
+
+#[test]
+fn test_closure_logging() {
+    use assimp::log::Severity;
+    use std::sync::{Arc, Mutex};
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let sink = messages.clone();
+    let mut log_stream = LogStream::from_fn(move |severity, msg| {
+        sink.lock().unwrap().push((severity, msg.to_owned()));
+    });
+    log_stream.attach();
+    assert!(log_stream.attached());
+
+    let importer = Importer::new();
+    let _ = importer.read_file("examples/non_existent_file.obj");
+    drop(log_stream);
+
+    let messages = messages.lock().unwrap();
+    assert!(messages.iter().any(|&(severity, _)| severity == Severity::Error));
+    assert!(messages.iter().all(|&(_, ref msg)| !msg.starts_with("Error,")));
+}
+
+#[test]
+fn test_closure_attaching_stream() {
+    use std::sync::{Arc, Mutex};
+
+    // Attaching and detaching streams from within a closure must not deadlock
+    let calls = Arc::new(Mutex::new(0));
+    let counter = calls.clone();
+    let mut log_stream = LogStream::from_fn(move |_, _| {
+        let mut inner = LogStream::from_fn(|_, _| ());
+        inner.attach();
+        inner.detach();
+        *counter.lock().unwrap() += 1;
+    });
+    log_stream.attach();
+
+    let importer = Importer::new();
+    let _ = importer.read_file("examples/non_existent_file.obj");
+    drop(log_stream);
+    assert!(*calls.lock().unwrap() > 0);
+}
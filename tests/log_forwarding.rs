@@ -0,0 +1,38 @@
+#![cfg(feature = "log")]
+// Kept apart from the other log tests, since it installs the global logger and checks the
+// verbosity, which other tests change.
+extern crate assimp;
+extern crate log;
+
+use assimp::Importer;
+use assimp::LogStream;
+
+#[test]
+fn test_forward_to_log() {
+    use log::{Log, Metadata, Record, LevelFilter};
+    use std::sync::Mutex;
+
+    struct TestLogger(Mutex<Vec<(String, log::Level)>>);
+
+    impl Log for TestLogger {
+        fn enabled(&self, _: &Metadata) -> bool { true }
+        fn log(&self, record: &Record) {
+            self.0.lock().unwrap().push((record.target().to_owned(), record.level()));
+        }
+        fn flush(&self) {}
+    }
+
+    static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Info);
+
+    assimp::log::forward_to_log();
+    assert!(!LogStream::verbose_logging());
+
+    let importer = Importer::new();
+    let _ = importer.read_file("examples/non_existent_file.obj");
+
+    let records = LOGGER.0.lock().unwrap();
+    assert!(records.iter().all(|&(ref target, _)| target == "assimp"));
+    assert!(records.iter().any(|&(_, level)| level == log::Level::Error));
+}
@@ -0,0 +1,36 @@
+// Kept apart from the other log tests, since detaching all streams would interfere with them.
+extern crate assimp;
+
+use assimp::{Importer, LogStream};
+use assimp::log::{self, StreamKind};
+
+#[test]
+fn test_log_registry() {
+    let mut stdout = LogStream::stdout();
+    stdout.attach();
+    let guard = log::attach(LogStream::from_fn(|_, _| ()));
+    assert!(stdout.attached());
+    assert_eq!(guard.kind(), &StreamKind::Closure);
+    assert_eq!(log::attached_streams(), vec![StreamKind::StdOut, StreamKind::Closure]);
+
+    let mut closure = guard.detach();
+    assert!(!closure.attached());
+    assert_eq!(log::attached_streams(), vec![StreamKind::StdOut]);
+
+    closure.attach();
+    log::detach_all();
+    assert!(!stdout.attached());
+    assert!(!closure.attached());
+    assert!(log::attached_streams().is_empty());
+
+    // Messages of each import are still collected
+    let (scene, messages) = Importer::new().read_file_with_log("examples/non_existent_file.obj");
+    assert!(scene.is_err());
+    assert!(!messages.is_empty());
+
+    // Detached streams can be attached again
+    stdout.attach();
+    assert_eq!(log::attached_streams(), vec![StreamKind::StdOut]);
+    drop(stdout);
+    assert!(log::attached_streams().is_empty());
+}
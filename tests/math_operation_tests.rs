@@ -0,0 +1,206 @@
+extern crate assimp;
+
+use assimp::math::*;
+use std::f32::consts::PI;
+
+const EPSILON: f32 = 1e-5;
+
+fn assert_approx_eq(a: f32, b: f32) {
+    assert!((a - b).abs() < EPSILON, "{} != {}", a, b);
+}
+
+fn assert_vector_eq(a: Vector3D, b: Vector3D) {
+    assert_approx_eq(a.x, b.x);
+    assert_approx_eq(a.y, b.y);
+    assert_approx_eq(a.z, b.z);
+}
+
+fn assert_matrix_eq(a: Matrix4x4, b: Matrix4x4) {
+    let a: [f32; 16] = [a.a1, a.a2, a.a3, a.a4, a.b1, a.b2, a.b3, a.b4,
+                        a.c1, a.c2, a.c3, a.c4, a.d1, a.d2, a.d3, a.d4];
+    let b: [f32; 16] = [b.a1, b.a2, b.a3, b.a4, b.b1, b.b2, b.b3, b.b4,
+                        b.c1, b.c2, b.c3, b.c4, b.d1, b.d2, b.d3, b.d4];
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert_approx_eq(*x, *y);
+    }
+}
+
+#[test]
+fn test_vector3_operators() {
+    let a = Vector3D::new(1.0, 2.0, 3.0);
+    let b = Vector3D::new(4.0, 5.0, 6.0);
+
+    assert_eq!(a + b, Vector3D::new(5.0, 7.0, 9.0));
+    assert_eq!(b - a, Vector3D::new(3.0, 3.0, 3.0));
+    assert_eq!(a * 2.0, Vector3D::new(2.0, 4.0, 6.0));
+    assert_eq!(b / 2.0, Vector3D::new(2.0, 2.5, 3.0));
+    assert_eq!(-a, Vector3D::new(-1.0, -2.0, -3.0));
+    assert_approx_eq(a.dot(b), 32.0);
+    assert_eq!(Vector3D::new(1.0, 0.0, 0.0).cross(Vector3D::new(0.0, 1.0, 0.0)),
+               Vector3D::new(0.0, 0.0, 1.0));
+    assert_approx_eq(Vector3D::new(3.0, 0.0, 4.0).length(), 5.0);
+    assert_approx_eq(a.normalize().length(), 1.0);
+}
+
+#[test]
+fn test_vector2_operators() {
+    let a = Vector2D::new(1.0, 2.0);
+    let b = Vector2D::new(3.0, 4.0);
+
+    assert_eq!(a + b, Vector2D::new(4.0, 6.0));
+    assert_eq!(b - a, Vector2D::new(2.0, 2.0));
+    assert_eq!(-a * 2.0, Vector2D::new(-2.0, -4.0));
+    assert_approx_eq(b.length(), 5.0);
+}
+
+#[test]
+fn test_matrix3_inverse() {
+    let m = Matrix3x3::new(2.0, 0.0, 1.0,
+                           1.0, 3.0, 0.0,
+                           0.0, 1.0, 4.0);
+    let product = m * m.inverse().unwrap();
+    let identity = Matrix3x3::identity();
+    assert_approx_eq(product.a1, identity.a1);
+    assert_approx_eq(product.a2, identity.a2);
+    assert_approx_eq(product.b2, identity.b2);
+    assert_approx_eq(product.c1, identity.c1);
+    assert_approx_eq(product.c3, identity.c3);
+    assert_approx_eq(m.determinant(), 25.0);
+
+    let singular = Matrix3x3::new(1.0, 2.0, 3.0,
+                                  2.0, 4.0, 6.0,
+                                  0.0, 1.0, 1.0);
+    assert!(singular.inverse().is_none());
+}
+
+#[test]
+fn test_matrix4_inverse_and_transpose() {
+    let m = Matrix4x4::new(1.0, 2.0, 0.0, 4.0,
+                           0.0, 1.0, 3.0, 0.0,
+                           2.0, 0.0, 1.0, 1.0,
+                           0.0, 0.0, 0.0, 1.0);
+    assert_approx_eq(m.determinant(), 13.0);
+    assert_matrix_eq(m * m.inverse().unwrap(), Matrix4x4::identity());
+    assert_matrix_eq(m.inverse().unwrap() * m, Matrix4x4::identity());
+    assert_matrix_eq(m.transpose().transpose(), m);
+    assert_eq!(m.transpose().a2, m.b1);
+    assert!(Matrix4x4::new(0.0, 0.0, 0.0, 0.0,
+                           0.0, 1.0, 0.0, 0.0,
+                           0.0, 0.0, 1.0, 0.0,
+                           0.0, 0.0, 0.0, 1.0).inverse().is_none());
+}
+
+#[test]
+fn test_matrix_operators() {
+    let a = Matrix3x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let b = Matrix3x3::identity();
+    assert_eq!(a + b, Matrix3x3::new(2.0, 2.0, 3.0, 4.0, 6.0, 6.0, 7.0, 8.0, 10.0));
+    assert_eq!(a - b, Matrix3x3::new(0.0, 2.0, 3.0, 4.0, 4.0, 6.0, 7.0, 8.0, 8.0));
+    assert_eq!(-b, Matrix3x3::new(-1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -1.0));
+    assert_eq!(a - a, a + -a);
+
+    let m = Matrix4x4::from_translation(Vector3D::new(1.0, 2.0, 3.0));
+    let i = Matrix4x4::identity();
+    assert_matrix_eq(m - i, Matrix4x4::new(0.0, 0.0, 0.0, 1.0,
+                                           0.0, 0.0, 0.0, 2.0,
+                                           0.0, 0.0, 0.0, 3.0,
+                                           0.0, 0.0, 0.0, 0.0));
+    assert_matrix_eq(m + -m, Matrix4x4::new(0.0, 0.0, 0.0, 0.0,
+                                            0.0, 0.0, 0.0, 0.0,
+                                            0.0, 0.0, 0.0, 0.0,
+                                            0.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_matrix4_transform_point() {
+    let translation = Matrix4x4::from_translation(Vector3D::new(1.0, 2.0, 3.0));
+    let scaling = Matrix4x4::from_scaling(Vector3D::new(2.0, 2.0, 2.0));
+    let p = Vector3D::new(1.0, 1.0, 1.0);
+
+    assert_vector_eq(translation * p, Vector3D::new(2.0, 3.0, 4.0));
+    // Scaling is applied first, then translation
+    assert_vector_eq((translation * scaling) * p, Vector3D::new(3.0, 4.0, 5.0));
+}
+
+#[test]
+fn test_matrix4_decompose() {
+    let scaling = Vector3D::new(1.0, 2.0, 3.0);
+    let rotation = Quaternion::from_axis_angle(Vector3D::new(0.0, 1.0, 0.0), PI / 3.0);
+    let translation = Vector3D::new(-4.0, 5.0, 6.0);
+
+    let m = Matrix4x4::compose(scaling, rotation, translation);
+    let (s, r, t) = m.decompose();
+    assert_vector_eq(s, scaling);
+    assert_vector_eq(t, translation);
+    assert_approx_eq(r.dot(rotation).abs(), 1.0);
+    assert_matrix_eq(Matrix4x4::compose(s, r, t), m);
+}
+
+#[test]
+fn test_quaternion_rotation() {
+    let q = Quaternion::from_axis_angle(Vector3D::new(0.0, 0.0, 1.0), PI / 2.0);
+    let v = Vector3D::new(1.0, 0.0, 0.0);
+
+    assert_vector_eq(q.rotate(v), Vector3D::new(0.0, 1.0, 0.0));
+    assert_vector_eq(q.to_matrix() * v, Vector3D::new(0.0, 1.0, 0.0));
+
+    let r = Quaternion::from_matrix(&q.to_matrix());
+    assert_approx_eq(r.dot(q).abs(), 1.0);
+    assert_approx_eq((q * q.conjugate()).w, 1.0);
+}
+
+#[test]
+fn test_quaternion_operators() {
+    let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let b = Quaternion::new(0.5, 0.5, 0.5, 0.5);
+    assert_eq!(a + b, Quaternion::new(1.5, 2.5, 3.5, 4.5));
+    assert_eq!(a - b, Quaternion::new(0.5, 1.5, 2.5, 3.5));
+    assert_eq!(a + -a, Quaternion::new(0.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_quaternion_slerp() {
+    let axis = Vector3D::new(0.0, 1.0, 0.0);
+    let a = Quaternion::identity();
+    let b = Quaternion::from_axis_angle(axis, PI / 2.0);
+
+    assert_approx_eq(a.slerp(b, 0.0).dot(a), 1.0);
+    assert_approx_eq(a.slerp(b, 1.0).dot(b), 1.0);
+
+    let half = a.slerp(b, 0.5);
+    assert_approx_eq(half.dot(Quaternion::from_axis_angle(axis, PI / 4.0)), 1.0);
+
+    let q = Quaternion::new(2.0, 0.0, 0.0, 0.0).normalize();
+    assert_eq!(q, Quaternion::identity());
+}
+
+#[test]
+fn test_aabb() {
+    let aabb = Aabb::from_points(vec![Vector3D::new(1.0, -2.0, 0.0),
+                                      Vector3D::new(-1.0, 2.0, 4.0),
+                                      Vector3D::new(0.0, 0.0, 1.0)]).unwrap();
+    assert_eq!(aabb, Aabb::new(Vector3D::new(-1.0, -2.0, 0.0), Vector3D::new(1.0, 2.0, 4.0)));
+    assert_eq!(aabb.center(), Vector3D::new(0.0, 0.0, 2.0));
+    assert_eq!(aabb.size(), Vector3D::new(2.0, 4.0, 4.0));
+    assert!(aabb.contains(Vector3D::new(0.5, 1.0, 3.0)));
+    assert!(!aabb.contains(Vector3D::new(0.5, 1.0, 5.0)));
+    assert!(Aabb::from_points(Vec::new()).is_none());
+
+    let moved = aabb.transform(&Matrix4x4::from_translation(Vector3D::new(1.0, 0.0, 0.0)));
+    assert_eq!(moved.min, Vector3D::new(0.0, -2.0, 0.0));
+    let rotated = aabb.transform(&Quaternion::from_axis_angle(Vector3D::new(0.0, 0.0, 1.0), PI / 2.0).to_matrix().into());
+    assert_vector_eq(rotated.size(), Vector3D::new(4.0, 2.0, 4.0));
+
+    let union = aabb.union(&moved);
+    assert_eq!(union.max, Vector3D::new(2.0, 2.0, 4.0));
+    assert_approx_eq(aabb.bounding_sphere().radius, 3.0);
+}
+
+#[test]
+fn test_bounding_sphere() {
+    let points = vec![Vector3D::new(-1.0, 0.0, 0.0), Vector3D::new(3.0, 0.0, 0.0),
+                      Vector3D::new(1.0, 1.0, 0.0)];
+    let sphere = BoundingSphere::from_points(points.clone()).unwrap();
+    assert_eq!(sphere.center, Vector3D::new(1.0, 0.5, 0.0));
+    assert!(points.iter().all(|&p| sphere.contains(p)));
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "mint")]
+extern crate assimp;
+extern crate mint;
+
+use assimp::math::*;
+
+#[test]
+fn test_matrix3_conversion() {
+    use mint::{ColumnMatrix3, RowMatrix3};
+    let rows = [[1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0],
+                [7.0, 8.0, 9.0]];
+
+    // row-major
+    let m1 = RowMatrix3::from(rows);
+    let m2 = Matrix3x3::new(1.0, 2.0, 3.0,
+                            4.0, 5.0, 6.0,
+                            7.0, 8.0, 9.0);
+    let m3 = Matrix3x3::from(m1);
+    let m4: RowMatrix3<f32> = m2.into();
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+
+    // column-major
+    let m1 = ColumnMatrix3::from(rows);
+    let m2 = Matrix3x3::new(1.0, 4.0, 7.0,
+                            2.0, 5.0, 8.0,
+                            3.0, 6.0, 9.0);
+    let m3 = Matrix3x3::from(m1);
+    let m4: ColumnMatrix3<f32> = m2.into();
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+}
+
+#[test]
+fn test_matrix4_conversion() {
+    use mint::{ColumnMatrix4, RowMatrix4};
+    let rows = [[1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0, 12.0],
+                [13.0, 14.0, 15.0, 16.0]];
+
+    // row-major
+    let m1 = RowMatrix4::from(rows);
+    let m2 = Matrix4x4::new(1.0, 2.0, 3.0, 4.0,
+                            5.0, 6.0, 7.0, 8.0,
+                            9.0, 10.0, 11.0, 12.0,
+                            13.0, 14.0, 15.0, 16.0);
+    let m3 = Matrix4x4::from(m1);
+    let m4: RowMatrix4<f32> = m2.into();
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+
+    // column-major
+    let m1 = ColumnMatrix4::from(rows);
+    let m2 = Matrix4x4::new(1.0, 5.0, 9.0, 13.0,
+                            2.0, 6.0, 10.0, 14.0,
+                            3.0, 7.0, 11.0, 15.0,
+                            4.0, 8.0, 12.0, 16.0);
+    let m3 = Matrix4x4::from(m1);
+    let m4: ColumnMatrix4<f32> = m2.into();
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+}
+
+#[test]
+fn test_quaternion_conversion() {
+    use mint::{Quaternion as MintQuaternion, Vector3};
+
+    let q1 = MintQuaternion { v: Vector3 { x: 2.0, y: 3.0, z: 4.0 }, s: 1.0 };
+    let q2 = Quaternion::from(q1);
+    let q3: MintQuaternion<f32> = q2.into();
+    assert_eq!(q2, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(q1, q3);
+}
+
+#[test]
+fn test_vector2_conversion() {
+    use mint::{Point2, Vector2};
+
+    // mint vector
+    let v1 = Vector2 { x: 1.0, y: 2.0 };
+    let v2 = Vector2D::from(v1);
+    let v3: Vector2<f32> = v2.into();
+    assert_eq!(v1, v3);
+
+    // mint point
+    let v1 = Point2 { x: 1.0, y: 2.0 };
+    let v2 = Vector2D::from(v1);
+    let v3: Point2<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_vector3_conversion() {
+    use mint::{Point3, Vector3};
+
+    // mint vector
+    let v1 = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+    let v2 = Vector3D::from(v1);
+    let v3: Vector3<f32> = v2.into();
+    assert_eq!(v1, v3);
+
+    // mint point
+    let v1 = Point3 { x: 1.0, y: 2.0, z: 3.0 };
+    let v2 = Vector3D::from(v1);
+    let v3: Point3<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_color3_conversion() {
+    use mint::Vector3;
+
+    let v1 = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+    let v2 = Color3D::from(v1);
+    let v3: Vector3<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_color4_conversion() {
+    use mint::Vector4;
+
+    let v1 = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    let v2 = Color4D::from(v1);
+    let v3: Vector4<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
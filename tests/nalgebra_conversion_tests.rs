@@ -0,0 +1,116 @@
+#![cfg(feature = "nalgebra")]
+extern crate assimp;
+extern crate nalgebra;
+
+use assimp::math::*;
+
+#[test]
+fn test_matrix3_conversion() {
+    use nalgebra::Matrix3;
+    // nalgebra constructors take elements in row-major order
+    let m1 = Matrix3::new(1.0, 2.0, 3.0,
+                          4.0, 5.0, 6.0,
+                          7.0, 8.0, 9.0);
+    let m2 = Matrix3x3::new(1.0, 2.0, 3.0,
+                            4.0, 5.0, 6.0,
+                            7.0, 8.0, 9.0);
+
+    let m3 = Matrix3x3::from(m1);
+    let m4: Matrix3<f32> = m2.into();
+
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+}
+
+#[test]
+fn test_matrix4_conversion() {
+    use nalgebra::Matrix4;
+    let m1 = Matrix4::new(1.0, 2.0, 3.0, 4.0,
+                          5.0, 6.0, 7.0, 8.0,
+                          9.0, 10.0, 11.0, 12.0,
+                          13.0, 14.0, 15.0, 16.0);
+    let m2 = Matrix4x4::new(1.0, 2.0, 3.0, 4.0,
+                            5.0, 6.0, 7.0, 8.0,
+                            9.0, 10.0, 11.0, 12.0,
+                            13.0, 14.0, 15.0, 16.0);
+
+    let m3 = Matrix4x4::from(m1);
+    let m4: Matrix4<f32> = m2.into();
+
+    assert_eq!(m1, m4);
+    assert_eq!(m2, m3);
+    // Translation lives in the last column for both
+    assert_eq!(m4[(0, 3)], m2.a4);
+}
+
+#[test]
+fn test_quaternion_conversion() {
+    use nalgebra::{Quaternion as NaQuaternion, UnitQuaternion};
+
+    let q1 = NaQuaternion::new(1.0, 2.0, 3.0, 4.0);
+    let q2 = Quaternion::from(q1);
+    let q3: NaQuaternion<f32> = q2.into();
+    assert_eq!(q2, Quaternion::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(q1, q3);
+
+    // unit quaternion
+    let q1 = UnitQuaternion::identity();
+    let q2 = Quaternion::from(q1);
+    let q3: UnitQuaternion<f32> = q2.into();
+    assert_eq!(q2, Quaternion::new(1.0, 0.0, 0.0, 0.0));
+    assert_eq!(q1, q3);
+}
+
+#[test]
+fn test_vector2_conversion() {
+    use nalgebra::{Point2, Vector2};
+
+    // nalgebra vector
+    let v1 = Vector2::new(1.0, 2.0);
+    let v2 = Vector2D::from(v1);
+    let v3: Vector2<f32> = v2.into();
+    assert_eq!(v1, v3);
+
+    // nalgebra point
+    let v1 = Point2::new(1.0, 2.0);
+    let v2 = Vector2D::from(v1);
+    let v3: Point2<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_vector3_conversion() {
+    use nalgebra::{Point3, Vector3};
+
+    // nalgebra vector
+    let v1 = Vector3::new(1.0, 2.0, 3.0);
+    let v2 = Vector3D::from(v1);
+    let v3: Vector3<f32> = v2.into();
+    assert_eq!(v1, v3);
+
+    // nalgebra point
+    let v1 = Point3::new(1.0, 2.0, 3.0);
+    let v2 = Vector3D::from(v1);
+    let v3: Point3<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_color3_conversion() {
+    use nalgebra::Vector3;
+
+    let v1 = Vector3::new(1.0, 2.0, 3.0);
+    let v2 = Color3D::from(v1);
+    let v3: Vector3<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
+
+#[test]
+fn test_color4_conversion() {
+    use nalgebra::Vector4;
+
+    let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let v2 = Color4D::from(v1);
+    let v3: Vector4<f32> = v2.into();
+    assert_eq!(v1, v3);
+}
@@ -13,3 +13,349 @@ fn test_scene_properties() {
     assert_eq!(scene.num_lights() as usize, scene.light_iter().len());
     assert_eq!(scene.num_cameras() as usize, scene.camera_iter().len());
 }
+
+#[test]
+fn test_memory_requirements() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let memory = scene.memory_requirements();
+    assert!(memory.meshes > 0);
+    assert!(memory.nodes > 0);
+    assert!(memory.total >= memory.meshes + memory.nodes + memory.materials);
+}
+
+#[test]
+fn test_pbr_material_from_phong() {
+    use assimp::scene::AlphaMode;
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    for material in scene.material_iter() {
+        let pbr = material.pbr();
+        assert!(!pbr.is_pbr);
+        assert_eq!(pbr.metallic.factor, 0.0);
+        assert!(pbr.roughness.factor > 0.0 && pbr.roughness.factor <= 1.0);
+        assert_eq!(pbr.alpha_mode, AlphaMode::Opaque);
+        if let Some(diffuse) = material.diffuse_color() {
+            assert_eq!(pbr.base_color.factor.r, diffuse.r);
+        }
+    }
+}
+
+#[test]
+fn test_material_textures() {
+    use assimp::scene::AiTextureType;
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    for material in scene.material_iter() {
+        let slots = material.textures(AiTextureType::Diffuse);
+        let paths: Vec<_> = material.texture_path()
+            .filter(|x| x.texture_type == AiTextureType::Diffuse)
+            .collect();
+        assert_eq!(slots.len(), paths.len());
+        for slot in &slots {
+            assert_eq!(slot.texture_type, AiTextureType::Diffuse);
+            assert!(!slot.path.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_material_property_values() {
+    use assimp::scene::PropertyValue;
+    use assimp::MaterialPropertyKey;
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let material = scene.material_iter().next().unwrap();
+    for prop in material.properties_iter() {
+        match (prop.key(), prop.value()) {
+            (MaterialPropertyKey::Name, PropertyValue::String(name)) => {
+                assert_eq!(name, material.name().unwrap().as_ref());
+            }
+            (MaterialPropertyKey::Name, value) => panic!("unexpected name {:?}", value),
+            (MaterialPropertyKey::DiffuseColor, PropertyValue::Float(color)) => {
+                assert!(color.len() >= 3);
+                assert_eq!(color[0], material.diffuse_color().unwrap().r);
+            }
+            _ => ()
+        }
+    }
+}
+
+#[test]
+fn test_material_property_keys() {
+    use assimp::MaterialPropertyKey;
+
+    assert_eq!(MaterialPropertyKey::from("$clr.diffuse"), MaterialPropertyKey::DiffuseColor);
+    assert_eq!(MaterialPropertyKey::from("$tex.file").as_str(), "$tex.file");
+    assert_eq!(MaterialPropertyKey::from("$mat.gltf.alphaMode"), MaterialPropertyKey::GltfAlphaMode);
+    assert_eq!(MaterialPropertyKey::from("$custom.key"),
+               MaterialPropertyKey::Raw("$custom.key".to_owned()));
+    assert_eq!(MaterialPropertyKey::Raw("$custom.key".to_owned()).as_str(), "$custom.key");
+}
+
+#[test]
+fn test_texture_resolver() {
+    use assimp::scene::{ResolvedTexture, TextureResolver};
+    use std::env;
+    use std::fs;
+
+    use std::process;
+
+    // Unique to this run, so concurrent runs don't share files
+    let root = env::temp_dir().join(format!("assimp_texture_resolver_{}", process::id()));
+    fs::create_dir_all(root.join("models/Textures")).unwrap();
+    fs::create_dir_all(root.join("shared")).unwrap();
+    fs::File::create(root.join("models/Textures/Wood.png")).unwrap();
+    fs::File::create(root.join("shared/metal.png")).unwrap();
+
+    let mut resolver = TextureResolver::new(root.join("models/house.obj"));
+    let wood = ResolvedTexture::File(root.join("models/Textures/Wood.png"));
+    assert_eq!(resolver.resolve("Textures/Wood.png"), wood);
+    assert_eq!(resolver.resolve("textures\\wood.png"), wood);
+    assert_eq!(resolver.resolve("C:\\Art\\House\\Textures\\Wood.png"), wood);
+    assert_eq!(resolver.resolve("*2"), ResolvedTexture::Embedded(2));
+    assert_eq!(resolver.resolve("metal.png"), ResolvedTexture::Missing);
+
+    resolver.add_search_dir(root.join("shared"));
+    assert_eq!(resolver.resolve("/home/artist/metal.png"),
+               ResolvedTexture::File(root.join("shared/metal.png")));
+
+    resolver.case_sensitive(true);
+    assert_eq!(resolver.resolve("textures/wood.png"), ResolvedTexture::Missing);
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_scene_texture_dependencies() {
+    use assimp::scene::TextureResolver;
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let dependencies = scene.texture_dependencies();
+    assert!(dependencies.iter().all(|x| !x.starts_with('*')));
+
+    // The box has no embedded textures, so both list the same references
+    let resolved = TextureResolver::new("examples/box.obj").resolve_scene(&scene);
+    let references: Vec<_> = resolved.into_iter().map(|(reference, _)| reference).collect();
+    assert_eq!(references, dependencies);
+}
+
+#[test]
+fn test_scene_dependencies() {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    let dependencies = scene.dependencies("examples/spider.obj").unwrap();
+    assert_eq!(dependencies[0], "spider.mtl");
+    assert_eq!(&dependencies[1..], &scene.texture_dependencies()[..]);
+
+    // Buffers are read from the model file, whatever the scene
+    let root = env::temp_dir().join(format!("assimp_dependencies_{}", process::id()));
+    fs::create_dir_all(&root).unwrap();
+    let gltf2 = r#"{"asset": {"version": "2.0"}, "buffers": [{"uri": "mesh\u0020a.bin",
+        "byteLength": 8}, {"uri": "data:application/octet-stream;base64,AAAA"}]}"#;
+    let gltf1 = r#"{"buffers": {"mesh": {"uri": "mesh.bin", "type": "arraybuffer"}}}"#;
+    let mut glb = b"glTF".to_vec();
+    for x in &[2, 20 + gltf1.len() as u32, gltf1.len() as u32, 0x4e4f534a] {
+        glb.extend_from_slice(&(*x as u32).to_le_bytes());
+    }
+    glb.extend_from_slice(gltf1.as_bytes());
+    fs::write(root.join("a.gltf"), gltf2).unwrap();
+    fs::write(root.join("b.gltf"), gltf1).unwrap();
+    fs::write(root.join("c.glb"), glb).unwrap();
+
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let textures = scene.texture_dependencies();
+    let without_textures = |path: &str| {
+        let dependencies = scene.dependencies(root.join(path)).unwrap();
+        assert!(dependencies.ends_with(&textures));
+        dependencies[..dependencies.len() - textures.len()].to_vec()
+    };
+    assert_eq!(without_textures("a.gltf"), vec!["mesh a.bin".to_owned()]);
+    assert_eq!(without_textures("b.gltf"), vec!["mesh.bin".to_owned()]);
+    assert_eq!(without_textures("c.glb"), vec!["mesh.bin".to_owned()]);
+    assert!(scene.dependencies(root.join("missing.gltf")).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_scene_bounds() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let bounds = scene.bounds().unwrap();
+    let sphere = scene.bounding_sphere().unwrap();
+    for mesh in scene.mesh_iter() {
+        let aabb = mesh.aabb().unwrap();
+        for v in mesh.vertex_iter() {
+            assert!(aabb.contains(v));
+            // The box isn't transformed by its node
+            assert!(bounds.contains(v));
+            assert!((v - sphere.center).length() <= sphere.radius + 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_mesh_accessors() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    for mesh in scene.mesh_iter() {
+        let material = mesh.material(&scene).unwrap();
+        assert!(material.name().is_some());
+        assert!(mesh.material_index() < scene.num_materials());
+
+        let channels = mesh.num_uv_channels() as usize;
+        for channel in 0..channels {
+            assert!(mesh.has_texture_coords(channel));
+            assert_eq!(mesh.num_uv_components(channel), Some(2));
+            assert_eq!(mesh.texture_coords_iter(channel).count(), mesh.num_vertices() as usize);
+        }
+        assert_eq!(mesh.num_uv_components(channels), None);
+
+        // Missing and out of range channels are empty rather than invalid
+        assert_eq!(mesh.num_color_sets(), 0);
+        assert_eq!(mesh.vertex_color_iter(0).count(), 0);
+        assert!(mesh.get_vertex_color(0, 0).is_none());
+        assert!(mesh.get_texture_coord(100, 0).is_none());
+        assert_eq!(mesh.texture_coords_iter(100).count(), 0);
+    }
+}
+
+#[test]
+fn test_scene_stats() {
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    let stats = scene.stats();
+    assert_eq!(stats.meshes.len(), scene.num_meshes() as usize);
+    assert_eq!(stats.materials, scene.num_materials());
+    assert_eq!(stats.external_textures as usize, scene.texture_dependencies().len());
+    assert!(stats.node_depth >= 1 && stats.nodes >= stats.node_depth);
+
+    let mut faces = 0;
+    for (mesh, mesh_stats) in scene.mesh_iter().zip(&stats.meshes) {
+        assert_eq!(mesh_stats.vertices, mesh.num_vertices());
+        assert_eq!(mesh_stats.faces, mesh.num_faces());
+        assert_eq!(mesh_stats.points + mesh_stats.lines + mesh_stats.triangles + mesh_stats.polygons,
+                   mesh.num_faces());
+        faces += mesh.num_faces();
+    }
+    assert_eq!(stats.faces, faces);
+
+    // The box is a single mesh of six quads, with the vertices of each face stored separately
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let stats = scene.stats();
+    assert_eq!(stats.meshes.len(), 1);
+    assert_eq!(stats.vertices, 24);
+    assert_eq!(stats.faces, 6);
+    assert_eq!(stats.polygons, 6);
+    assert_eq!(stats.triangles + stats.lines + stats.points, 0);
+    assert_eq!(stats.materials, 2);
+    assert_eq!(stats.external_textures, 0);
+    assert_eq!((stats.bones, stats.animations, stats.cameras, stats.lights), (0, 0, 0, 0));
+    assert_eq!((stats.nodes, stats.node_depth), (2, 2));
+
+    // Triangulating splits each quad in two
+    let mut importer = Importer::new();
+    importer.triangulate(true);
+    let stats = importer.read_file("examples/box.obj").unwrap().stats();
+    assert_eq!((stats.faces, stats.triangles, stats.polygons), (12, 12, 0));
+}
+
+#[test]
+fn test_scene_dump() {
+    use assimp::scene::DumpOptions;
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/box.obj").unwrap();
+    let dump = scene.dump(&DumpOptions::default());
+
+    // Dumps are deterministic across imports
+    let other = importer.read_file("examples/box.obj").unwrap();
+    assert_eq!(dump, other.dump(&DumpOptions::default()));
+
+    assert!(dump.starts_with("scene\n"));
+    assert!(dump.contains("\n  mesh 0\n"));
+    assert!(dump.contains("\n  material 0\n"));
+    assert!(dump.contains("    vertices: "));
+    assert!(dump.contains("    faces: "));
+
+    let options = DumpOptions { precision: 1, hashes: false, materials: false, ..Default::default() };
+    let short = scene.dump(&options);
+    assert!(!short.contains('#'));
+    assert!(!short.contains("$clr.diffuse"));
+    assert!(short.len() < dump.len());
+
+    // Debug output of a view is its section of the dump
+    let mesh = format!("{:?}", scene.mesh(0).unwrap());
+    assert!(mesh.starts_with("mesh\n"));
+    let mesh_section: Vec<&str> = mesh.lines().skip(1).collect();
+    assert!(dump.contains(&mesh_section.join("\n  ")));
+}
+
+#[test]
+fn test_scene_dump_golden() {
+    use assimp::scene::DumpOptions;
+    use std::env;
+    use std::fs;
+
+    // Run with ASSIMP_UPDATE_GOLDEN=1 to update the expected dump after an intended change
+    let path = "tests/data/box.obj.dump";
+    let importer = Importer::new();
+    let dump = importer.read_file("examples/box.obj").unwrap().dump(&DumpOptions::default());
+    if env::var_os("ASSIMP_UPDATE_GOLDEN").is_some() {
+        fs::write(path, &dump).unwrap();
+    }
+    assert_eq!(dump, fs::read_to_string(path).unwrap());
+}
+
+#[test]
+fn test_scene_diff() {
+    use assimp::scene::{DiffOptions, Difference, MeshAttribute};
+
+    let importer = Importer::new();
+    let scene = importer.read_file("examples/spider.obj").unwrap();
+    let same = importer.read_file("examples/spider.obj").unwrap();
+    let diff = scene.diff(&same, &DiffOptions::default());
+    assert!(diff.is_empty(), "{}", diff);
+
+    let mut importer = Importer::new();
+    importer.generate_normals(|x| x.enable = true);
+    let box_scene = Importer::new().read_file("examples/box.obj").unwrap();
+    let with_normals = importer.read_file("examples/box.obj").unwrap();
+    let diff = box_scene.diff(&with_normals, &DiffOptions::default());
+    if box_scene.mesh(0).unwrap().normals.is_null() {
+        assert!(diff.differences.contains(
+            &Difference::MeshAttributeAdded { mesh: 0, attribute: MeshAttribute::Normals }));
+    }
+
+    let diff = box_scene.diff(&scene, &DiffOptions::with_tolerance(1.0));
+    assert!(!diff.is_empty());
+    assert_eq!(diff.to_string().lines().count(), diff.differences.len());
+}
+
+#[test]
+fn test_scene_diff_nan() {
+    use assimp::scene::{DiffOptions, Difference, MeshAttribute};
+
+    // The OBJ importer doesn't parse NaN, the OFF importer does
+    let triangle = |x: &str| format!("OFF\n3 1 0\n{} 0 0\n1 0 0\n0 1 0\n3 0 1 2\n", x);
+    let importer = Importer::new();
+    let numbers = importer.read_string(&triangle("0")).unwrap();
+    let nans = importer.read_string(&triangle("nan")).unwrap();
+    assert!(nans.mesh(0).unwrap().vertex_iter().any(|v| v.x.is_nan()));
+
+    let diff = numbers.diff(&nans, &DiffOptions::default());
+    assert!(diff.differences.iter().any(|x| match *x {
+        Difference::MeshAttribute { attribute: MeshAttribute::Positions, max_error, .. } => {
+            max_error.is_infinite()
+        }
+        _ => false
+    }), "{}", diff);
+    assert!(nans.diff(&nans, &DiffOptions::default()).is_empty());
+}
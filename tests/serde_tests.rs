@@ -0,0 +1,64 @@
+#![cfg(feature = "serde")]
+
+extern crate assimp;
+extern crate serde_json;
+
+use assimp::import::structs::*;
+use assimp::math::*;
+
+#[test]
+fn test_vector_and_color_round_trip() {
+    let v2 = Vector2D::new(1.0, 2.0);
+    let v3 = Vector3D::new(1.0, 2.0, 3.0);
+    let c3 = Color3D::new(0.25, 0.5, 0.75);
+    let c4 = Color4D::new(0.25, 0.5, 0.75, 1.0);
+
+    assert_eq!(serde_json::to_string(&v3).unwrap(), "[1.0,2.0,3.0]");
+    assert_eq!(serde_json::from_str::<Vector2D>(&serde_json::to_string(&v2).unwrap()).unwrap(), v2);
+    assert_eq!(serde_json::from_str::<Vector3D>(&serde_json::to_string(&v3).unwrap()).unwrap(), v3);
+    assert_eq!(serde_json::from_str::<Color3D>(&serde_json::to_string(&c3).unwrap()).unwrap(), c3);
+    assert_eq!(serde_json::from_str::<Color4D>(&serde_json::to_string(&c4).unwrap()).unwrap(), c4);
+}
+
+#[test]
+fn test_quaternion_round_trip() {
+    let q = Quaternion::new(0.5, 0.5, 0.5, 0.5);
+    let json = serde_json::to_string(&q).unwrap();
+    assert_eq!(json, r#"{"w":0.5,"x":0.5,"y":0.5,"z":0.5}"#);
+    assert_eq!(serde_json::from_str::<Quaternion>(&json).unwrap(), q);
+}
+
+#[test]
+fn test_matrix_round_trip() {
+    let m3 = Matrix3x3::new(1.0, 2.0, 3.0,
+                            4.0, 5.0, 6.0,
+                            7.0, 8.0, 9.0);
+    let m4 = Matrix4x4::from_translation(Vector3D::new(1.0, 2.0, 3.0));
+
+    let json = serde_json::to_string(&m3).unwrap();
+    assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+    assert_eq!(serde_json::from_str::<Matrix3x3>(&json).unwrap(), m3);
+    assert_eq!(serde_json::from_str::<Matrix4x4>(&serde_json::to_string(&m4).unwrap()).unwrap(), m4);
+}
+
+#[test]
+fn test_import_structs_round_trip() {
+    let args = RemoveComponent {
+        enable: true,
+        components: vec![ComponentType::Normals, ComponentType::Colors]
+    };
+    let json = serde_json::to_string(&args).unwrap();
+    assert_eq!(serde_json::from_str::<RemoveComponent>(&json).unwrap(), args);
+
+    let args = PreTransformVertices { enable: true, normalize: true, ..Default::default() };
+    let json = serde_json::to_string(&args).unwrap();
+    assert_eq!(serde_json::from_str::<PreTransformVertices>(&json).unwrap(), args);
+}
+
+#[test]
+fn test_import_structs_missing_fields_use_defaults() {
+    let args: GenerateNormals = serde_json::from_str(r#"{"enable":true}"#).unwrap();
+    assert!(args.enable);
+    assert!(!args.smooth);
+    assert_eq!(args.max_smoothing_angle, 175.0);
+}